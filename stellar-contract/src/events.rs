@@ -8,6 +8,9 @@ const WASTE_TRANSFERRED: Symbol = symbol_short!("transfer");
 const WASTE_CONFIRMED: Symbol = symbol_short!("confirmed");
 const PARTICIPANT_REGISTERED: Symbol = symbol_short!("reg");
 const TOKENS_REWARDED: Symbol = symbol_short!("rewarded");
+const CONFIG: Symbol = symbol_short!("config");
+const SEASON: Symbol = symbol_short!("season");
+const REWARD: Symbol = symbol_short!("reward");
 
 /// Emit event when waste is registered
 pub fn emit_waste_registered(
@@ -51,6 +54,14 @@ pub fn emit_waste_transferred(
     );
 }
 
+/// Emit event when a waste's owner updates its location in place
+pub fn emit_waste_relocated(env: &Env, waste_id: u128, latitude: i128, longitude: i128) {
+    env.events().publish(
+        (symbol_short!("waste"), symbol_short!("relocated")),
+        (waste_id, latitude, longitude),
+    );
+}
+
 /// Emit event when waste is confirmed by a third party
 pub fn emit_waste_confirmed(
     env: &Env,
@@ -79,14 +90,62 @@ pub fn emit_participant_registered(
 }
 
 /// Emit event when tokens are rewarded
+/// `incentive_id` is 0 when the reward comes from base points only
 pub fn emit_tokens_rewarded(
     env: &Env,
     recipient: &Address,
     amount: u128,
     waste_id: u64,
+    incentive_id: u64,
 ) {
     env.events().publish(
         (TOKENS_REWARDED, recipient),
+        (amount, waste_id, incentive_id),
+    );
+}
+
+/// Emit event for one recipient's share of a distributed reward
+/// Emitted once per recipient, in chain order
+pub fn emit_reward_share(env: &Env, recipient: &Address, amount: u128, waste_id: u64) {
+    env.events().publish(
+        (REWARD, symbol_short!("share"), recipient),
         (amount, waste_id),
     );
 }
+
+/// Emit event when the charity address is changed
+pub fn emit_charity_changed(env: &Env, new_charity: &Address) {
+    env.events().publish(
+        (CONFIG, Symbol::new(env, "charity_changed")),
+        new_charity,
+    );
+}
+
+/// Emit event when a new season starts
+pub fn emit_season_reset(env: &Env, participants_reset: u32) {
+    env.events().publish(
+        (SEASON, symbol_short!("reset")),
+        (participants_reset, env.ledger().timestamp()),
+    );
+}
+
+/// Emit event when the admin verifies a material directly
+pub fn emit_material_admin_verified(env: &Env, material_id: u64, admin: &Address) {
+    env.events().publish(
+        (symbol_short!("material"), Symbol::new(env, "admin_verified")),
+        (material_id, admin),
+    );
+}
+
+/// Emit event when a participant's role changes
+pub fn emit_role_changed(
+    env: &Env,
+    address: &Address,
+    old_role: ParticipantRole,
+    new_role: ParticipantRole,
+) {
+    env.events().publish(
+        (Symbol::new(env, "participant"), Symbol::new(env, "role_changed")),
+        (address, old_role, new_role),
+    );
+}
@@ -2,14 +2,15 @@
 
 mod events;
 mod types;
+mod validation;
 
 pub use types::{
-    Incentive, Material, ParticipantRole, RecyclingStats, TransferItemType, TransferRecord, TransferStatus,
-    Waste, WasteTransfer, WasteType,
+    AutoRenew, Incentive, IncentiveMode, IncentiveRenewal, Material, ParticipantRole, RecyclingStats, RoundingSink, StorageKey, TransferItemType, TransferRecord, TransferStatus,
+    Waste, WasteTransfer, WasteType, WeightUnit,
 };
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec,
 };
 
 // Storage keys
@@ -19,8 +20,50 @@ const COLLECTOR_PCT: Symbol = symbol_short!("COL_PCT");
 const OWNER_PCT: Symbol = symbol_short!("OWN_PCT");
 const TOTAL_WEIGHT: Symbol = symbol_short!("TOT_WGT");
 const TOTAL_TOKENS: Symbol = symbol_short!("TOT_TKN");
+const TOTAL_UNCLAIMED: Symbol = symbol_short!("TOT_UNCL");
+const TOTAL_POINTS: Symbol = symbol_short!("TOT_PTS");
 const REENTRANCY_GUARD: Symbol = symbol_short!("RE_GUARD");
 const TOKEN_ADDR: Symbol = symbol_short!("TKN_ADDR");
+const MIN_INC_REWARD: Symbol = symbol_short!("MIN_RWD");
+const ALLOWED_TYPES: Symbol = symbol_short!("ALLOW_WT");
+const REVOKE_WINDOW: Symbol = symbol_short!("REVOKE_W");
+const MAX_SPLITS: Symbol = symbol_short!("MAX_SPLT");
+const SUBMITTER_PCT: Symbol = symbol_short!("SUB_PCT");
+const VERIFIER_REWARD: Symbol = symbol_short!("VER_RWD");
+const AUTO_CONFIRM: Symbol = symbol_short!("AUTO_CNF");
+const CONFIRMER_REWARD: Symbol = symbol_short!("CNF_RWD");
+const MIN_SUBMISSIONS: Symbol = symbol_short!("MIN_SUBS");
+const BASE_POINTS: Symbol = symbol_short!("BASE_PTS");
+const TRANSFER_FEE: Symbol = symbol_short!("TRN_FEE");
+const MIN_REWARD_WEIGHT: Symbol = symbol_short!("MIN_RWGT");
+const MAX_TRANSFERS: Symbol = symbol_short!("MAX_TRNS");
+const ROUNDING_SINK: Symbol = symbol_short!("RND_SINK");
+const TREASURY_BALANCE: Symbol = symbol_short!("TREASURY");
+const TOTAL_BURNED: Symbol = symbol_short!("TOT_BURN");
+const CHARITY_ACCRUED: Symbol = symbol_short!("CHR_ACCR");
+const MAX_PARTICIPANTS: Symbol = symbol_short!("MAX_PART");
+const WEIGHT_UNIT: Symbol = symbol_short!("WGT_UNIT");
+const HALVING_INTERVAL: Symbol = symbol_short!("HALVING");
+const VERIFICATION_COUNT: Symbol = symbol_short!("VERIF_CNT");
+const WASTE_EXPIRY: Symbol = symbol_short!("WST_EXP");
+const TOTAL_ACTIVE_WASTES: Symbol = symbol_short!("TOT_ACTV");
+const REQUIRE_CONFIRM: Symbol = symbol_short!("REQ_CNF");
+const RECENT_SUBMISSIONS: Symbol = symbol_short!("RECENT");
+const COLLECTOR_VERIFY: Symbol = symbol_short!("COL_VRFY");
+const MIN_HOPS: Symbol = symbol_short!("MIN_HOPS");
+const HOP_BONUS: Symbol = symbol_short!("HOP_BONUS");
+const INFINITE_BONUS: Symbol = symbol_short!("INF_BONUS");
+const COORD_SCALE: Symbol = symbol_short!("CRD_SCALE");
+
+// Default reward split used until percentages are configured
+const DEFAULT_COLLECTOR_PCT: u32 = 5;
+const DEFAULT_OWNER_PCT: u32 = 50;
+// Default cap on the number of pieces a waste can be split into
+const DEFAULT_MAX_SPLITS: u32 = 10;
+// Default coordinate units per degree (microdegrees)
+const DEFAULT_COORDINATE_SCALE: u64 = 1_000_000;
+// Number of material IDs kept in the global recent-submissions feed
+const RECENT_SUBMISSIONS_CAP: u32 = 20;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -44,6 +87,43 @@ pub struct ParticipantInfo {
     pub stats: RecyclingStats,
 }
 
+/// Snapshot of the contract's current configuration
+/// Percentages report the effective values used for reward distribution
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractConfig {
+    pub admin: Option<Address>,
+    pub charity: Option<Address>,
+    pub token_address: Option<Address>,
+    pub collector_percentage: u32,
+    pub owner_percentage: u32,
+    /// Explicit submitter share; None means the submitter receives the remainder
+    pub submitter_percentage: Option<u32>,
+    pub min_incentive_reward: u64,
+    pub allowed_waste_types: Vec<WasteType>,
+    pub verification_revoke_window: u64,
+    pub max_splits: u32,
+    pub verifier_reward_points: u64,
+    pub auto_confirm_on_verify: bool,
+    pub confirmer_reward_points: u64,
+    pub min_submissions_for_rewards: u32,
+    pub base_points_per_submission: u64,
+    pub transfer_fee_points: u64,
+    pub min_reward_weight: u64,
+    pub max_transfers_per_waste: u32,
+    pub rounding_sink: RoundingSink,
+    pub max_participants: u32,
+    pub weight_unit: WeightUnit,
+    pub reward_halving_interval: u64,
+    pub waste_expiry: u64,
+    pub require_confirmation_to_verify: bool,
+    pub allow_collector_verification: bool,
+    pub min_hops_for_bonus: u32,
+    pub hop_bonus_points: u64,
+    pub infinite_recyclable_bonus_pct: u32,
+    pub coordinate_scale: u64,
+}
+
 #[contract]
 pub struct ScavengerContract;
 
@@ -68,15 +148,41 @@ impl ScavengerContract {
         env.storage().instance().get(&ADMIN).expect("Admin not set")
     }
 
-    /// Check if caller is admin
-    fn require_admin(env: &Env, caller: &Address) {
-        let admin: Address = env.storage().instance().get(&ADMIN).expect("Admin not set");
-
-        if admin != *caller {
-            panic!("Unauthorized: caller is not admin");
+    /// Get all configured values in a single call
+    pub fn get_config(env: Env) -> ContractConfig {
+        let storage = env.storage().instance();
+
+        ContractConfig {
+            admin: storage.get(&ADMIN),
+            charity: storage.get(&CHARITY),
+            token_address: storage.get(&TOKEN_ADDR),
+            collector_percentage: storage.get(&COLLECTOR_PCT).unwrap_or(DEFAULT_COLLECTOR_PCT),
+            owner_percentage: storage.get(&OWNER_PCT).unwrap_or(DEFAULT_OWNER_PCT),
+            submitter_percentage: storage.get(&SUBMITTER_PCT),
+            min_incentive_reward: storage.get(&MIN_INC_REWARD).unwrap_or(0),
+            allowed_waste_types: storage.get(&ALLOWED_TYPES).unwrap_or(Vec::new(&env)),
+            verification_revoke_window: storage.get(&REVOKE_WINDOW).unwrap_or(0),
+            max_splits: storage.get(&MAX_SPLITS).unwrap_or(DEFAULT_MAX_SPLITS),
+            verifier_reward_points: storage.get(&VERIFIER_REWARD).unwrap_or(0),
+            auto_confirm_on_verify: storage.get(&AUTO_CONFIRM).unwrap_or(false),
+            confirmer_reward_points: storage.get(&CONFIRMER_REWARD).unwrap_or(0),
+            min_submissions_for_rewards: storage.get(&MIN_SUBMISSIONS).unwrap_or(0),
+            base_points_per_submission: storage.get(&BASE_POINTS).unwrap_or(0),
+            transfer_fee_points: storage.get(&TRANSFER_FEE).unwrap_or(0),
+            min_reward_weight: storage.get(&MIN_REWARD_WEIGHT).unwrap_or(0),
+            max_transfers_per_waste: storage.get(&MAX_TRANSFERS).unwrap_or(0),
+            rounding_sink: Self::get_rounding_sink(env.clone()),
+            max_participants: storage.get(&MAX_PARTICIPANTS).unwrap_or(0),
+            weight_unit: storage.get(&WEIGHT_UNIT).unwrap_or(WeightUnit::Grams),
+            reward_halving_interval: storage.get(&HALVING_INTERVAL).unwrap_or(0),
+            waste_expiry: storage.get(&WASTE_EXPIRY).unwrap_or(0),
+            require_confirmation_to_verify: storage.get(&REQUIRE_CONFIRM).unwrap_or(false),
+            allow_collector_verification: storage.get(&COLLECTOR_VERIFY).unwrap_or(false),
+            min_hops_for_bonus: storage.get(&MIN_HOPS).unwrap_or(0),
+            hop_bonus_points: storage.get(&HOP_BONUS).unwrap_or(0),
+            infinite_recyclable_bonus_pct: storage.get(&INFINITE_BONUS).unwrap_or(0),
+            coordinate_scale: storage.get(&COORD_SCALE).unwrap_or(DEFAULT_COORDINATE_SCALE),
         }
-
-        caller.require_auth();
     }
 
     /// Reentrancy guard lock.
@@ -116,38 +222,36 @@ impl ScavengerContract {
 
     /// Verify that the caller is a registered manufacturer
     /// Panics with "Caller is not a manufacturer" if not a manufacturer
-    /// Panics with "Caller is not a registered participant" if not registered
+    /// Panics with "Participant not registered" if not registered
     fn only_manufacturer(env: &Env, caller: &Address) {
         caller.require_auth();
-        
-        let key = (caller.clone(),);
-        let participant: Participant = env
-            .storage()
-            .instance()
-            .get(&key)
-            .expect("Caller is not a registered participant");
-        
-        if !participant.is_registered {
-            panic!("Caller is not a registered participant");
-        }
+
+        let participant = Self::get_registered_participant(env, caller);
         
         if !participant.role.can_manufacture() {
             panic!("Caller is not a manufacturer");
         }
     }
 
-    /// Verify that the caller is the contract administrator
-    /// Panics with "Caller is not the contract admin" if not admin
-    /// Panics with "Contract admin has not been set" if admin not configured
-    fn only_admin(env: &Env, caller: &Address) {
-        caller.require_auth();
-        
+    /// Load the contract administrator and require its authorization
+    /// Panics with "Admin not initialized" if admin not configured
+    fn require_admin(env: &Env) -> Address {
         let admin: Address = env
             .storage()
             .instance()
             .get(&ADMIN)
-            .expect("Contract admin has not been set");
-        
+            .expect("Admin not initialized");
+
+        admin.require_auth();
+        admin
+    }
+
+    /// Verify that the caller is the contract administrator
+    /// Panics with "Caller is not the contract admin" if not admin
+    /// Panics with "Admin not initialized" if admin not configured
+    fn only_admin(env: &Env, caller: &Address) {
+        let admin = Self::require_admin(env);
+
         if caller != &admin {
             panic!("Caller is not the contract admin");
         }
@@ -170,19 +274,24 @@ impl ScavengerContract {
         }
     }
 
-    // ========== Reentrancy Guard Functions ==========
+    /// Verify that the caller owns the specified waste item or is a transfer delegate of its owner
+    /// Returns the waste's owner; panics like `only_waste_owner` otherwise
+    fn only_waste_owner_or_delegate(env: &Env, caller: &Address, waste_id: u128) -> Address {
+        caller.require_auth();
 
-    /// Acquire reentrancy lock
-    fn lock(env: &Env) {
-        if env.storage().instance().get::<Symbol, bool>(&REENTRANCY_GUARD).unwrap_or(false) {
-            panic!("Reentrancy detected");
+        let waste: Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste item not found");
+
+        if &waste.current_owner != caller
+            && !Self::get_transfer_delegates(env, &waste.current_owner).contains(caller)
+        {
+            panic!("Caller is not the owner of this waste item");
         }
-        env.storage().instance().set(&REENTRANCY_GUARD, &true);
-    }
 
-    /// Release reentrancy lock
-    fn unlock(env: &Env) {
-        env.storage().instance().set(&REENTRANCY_GUARD, &false);
+        waste.current_owner
     }
 
     // ========== Charity Contract Functions ==========
@@ -200,6 +309,20 @@ impl ScavengerContract {
         env.storage().instance().set(&CHARITY, &charity_address);
     }
 
+    /// Update the charity address (admin only)
+    /// Subsequent donations are routed to the new address
+    pub fn set_charity_address(env: Env, admin: Address, new_charity: Address) {
+        Self::only_admin(&env, &admin);
+
+        if new_charity == admin {
+            panic!("Charity address cannot be the same as admin");
+        }
+
+        env.storage().instance().set(&CHARITY, &new_charity);
+
+        events::emit_charity_changed(&env, &new_charity);
+    }
+
     /// Get the charity contract address
     pub fn get_charity_contract(env: Env) -> Option<Address> {
         env.storage().instance().get(&CHARITY)
@@ -260,7 +383,8 @@ impl ScavengerContract {
         Self::only_admin(&env, &admin);
         
         // Validate percentages sum
-        if collector_percentage + owner_percentage > 100 {
+        let submitter_pct: u32 = env.storage().instance().get(&SUBMITTER_PCT).unwrap_or(0);
+        if collector_percentage + owner_percentage + submitter_pct > 100 {
             panic!("Total percentages cannot exceed 100");
         }
 
@@ -285,9 +409,10 @@ impl ScavengerContract {
         Self::only_admin(&env, &admin);
         
         // Get current owner percentage to validate total
-        let owner_pct: u32 = env.storage().instance().get(&OWNER_PCT).unwrap_or(0);
+        let owner_pct: u32 = env.storage().instance().get(&OWNER_PCT).unwrap_or(DEFAULT_OWNER_PCT);
+        let submitter_pct: u32 = env.storage().instance().get(&SUBMITTER_PCT).unwrap_or(0);
 
-        if new_percentage + owner_pct > 100 {
+        if new_percentage + owner_pct + submitter_pct > 100 {
             panic!("Total percentages cannot exceed 100");
         }
 
@@ -301,20 +426,76 @@ impl ScavengerContract {
         Self::only_admin(&env, &admin);
         
         // Get current collector percentage to validate total
-        let collector_pct: u32 = env.storage().instance().get(&COLLECTOR_PCT).unwrap_or(0);
+        let collector_pct: u32 = env
+            .storage()
+            .instance()
+            .get(&COLLECTOR_PCT)
+            .unwrap_or(DEFAULT_COLLECTOR_PCT);
+        let submitter_pct: u32 = env.storage().instance().get(&SUBMITTER_PCT).unwrap_or(0);
 
-        if collector_pct + new_percentage > 100 {
+        if collector_pct + new_percentage + submitter_pct > 100 {
             panic!("Total percentages cannot exceed 100");
         }
 
         env.storage().instance().set(&OWNER_PCT, &new_percentage);
     }
 
+    /// Set an explicit submitter percentage (admin only)
+    /// Once set, any rounding leftover goes to charity unless another rounding sink is configured
+    pub fn set_submitter_percentage(env: Env, admin: Address, new_percentage: u32) {
+        Self::only_admin(&env, &admin);
+
+        let collector_pct: u32 = env
+            .storage()
+            .instance()
+            .get(&COLLECTOR_PCT)
+            .unwrap_or(DEFAULT_COLLECTOR_PCT);
+        let owner_pct: u32 = env.storage().instance().get(&OWNER_PCT).unwrap_or(DEFAULT_OWNER_PCT);
+
+        if collector_pct + owner_pct + new_percentage > 100 {
+            panic!("Total percentages cannot exceed 100");
+        }
+
+        env.storage().instance().set(&SUBMITTER_PCT, &new_percentage);
+    }
+
+    /// Get the submitter percentage (None if the submitter receives the remainder)
+    pub fn get_submitter_percentage(env: Env) -> Option<u32> {
+        env.storage().instance().get(&SUBMITTER_PCT)
+    }
+
+    /// Set where rounding leftovers from reward distribution are sent (admin only)
+    pub fn set_rounding_sink(env: Env, admin: Address, sink: RoundingSink) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&ROUNDING_SINK, &sink);
+    }
+
+    /// Get where rounding leftovers from reward distribution are sent
+    /// Until configured, leftovers go to the submitter, or to charity once an explicit
+    /// submitter percentage is set
+    pub fn get_rounding_sink(env: Env) -> RoundingSink {
+        match env.storage().instance().get(&ROUNDING_SINK) {
+            Some(sink) => sink,
+            None if env.storage().instance().has(&SUBMITTER_PCT) => RoundingSink::Charity,
+            None => RoundingSink::Recycler,
+        }
+    }
+
+    /// Get the rounding leftovers held in the treasury
+    pub fn get_treasury_balance(env: Env) -> u128 {
+        env.storage().instance().get(&TREASURY_BALANCE).unwrap_or(0)
+    }
+
+    /// Get the rounding leftovers burned instead of distributed
+    pub fn get_total_burned(env: Env) -> u128 {
+        env.storage().instance().get(&TOTAL_BURNED).unwrap_or(0)
+    }
+
     // ========== Token Management Functions ==========
 
     /// Set the token contract address (admin only)
     pub fn set_token_address(env: Env, admin: Address, token_address: Address) {
-        Self::require_admin(&env, &admin);
+        Self::only_admin(&env, &admin);
         env.storage().instance().set(&TOKEN_ADDR, &token_address);
     }
 
@@ -380,12 +561,388 @@ impl ScavengerContract {
         env.storage().instance().set(&TOTAL_TOKENS, &new_total);
 
         // Emit token reward event
-        events::emit_tokens_rewarded(&env, &recipient, amount as u128, waste_id);
+        events::emit_tokens_rewarded(&env, &recipient, amount as u128, waste_id, 0);
 
         // Release lock
         Self::unlock(&env);
     }
 
+    // ========== Reward Claim Functions ==========
+
+    /// Get the rewards a participant has earned but not yet claimed
+    pub fn get_unclaimed_rewards(env: Env, participant: Address) -> u64 {
+        env.storage()
+            .instance()
+            .get(&("unclaimed", participant))
+            .unwrap_or(0)
+    }
+
+    /// Get the total earned but unclaimed rewards across all participants
+    pub fn get_total_unclaimed(env: Env) -> u64 {
+        env.storage().instance().get(&TOTAL_UNCLAIMED).unwrap_or(0)
+    }
+
+    /// Claim all outstanding rewards for a participant
+    /// Returns the amount claimed
+    pub fn claim_rewards(env: Env, participant: Address) -> u64 {
+        participant.require_auth();
+
+        let amount = Self::get_unclaimed_rewards(env.clone(), participant.clone());
+        if amount == 0 {
+            panic!("No rewards to claim");
+        }
+
+        env.storage()
+            .instance()
+            .set(&("unclaimed", participant.clone()), &0u64);
+
+        let total = Self::get_total_unclaimed(env.clone());
+        env.storage()
+            .instance()
+            .set(&TOTAL_UNCLAIMED, &total.saturating_sub(amount));
+
+        env.events().publish(
+            (symbol_short!("claimed"), participant),
+            (amount, env.ledger().timestamp()),
+        );
+
+        amount
+    }
+
+    /// Credit earned rewards to a participant's unclaimed balance and the global total
+    fn add_unclaimed_rewards(env: &Env, participant: &Address, amount: u64) {
+        let key = ("unclaimed", participant.clone());
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(
+            &key,
+            &current.checked_add(amount).expect("Overflow in unclaimed rewards"),
+        );
+
+        let total: u64 = env.storage().instance().get(&TOTAL_UNCLAIMED).unwrap_or(0);
+        env.storage().instance().set(
+            &TOTAL_UNCLAIMED,
+            &total.checked_add(amount).expect("Overflow in total unclaimed"),
+        );
+    }
+
+    // ========== Transfer Configuration Functions ==========
+
+    /// Set the fee, in reward points, charged to the sender of each waste transfer (admin only)
+    /// A value of 0 disables transfer fees
+    pub fn set_transfer_fee_points(env: Env, admin: Address, fee: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&TRANSFER_FEE, &fee);
+    }
+
+    /// Get the fee, in reward points, charged per waste transfer
+    pub fn get_transfer_fee_points(env: Env) -> u64 {
+        env.storage().instance().get(&TRANSFER_FEE).unwrap_or(0)
+    }
+
+    /// Get the total transfer fees and rounding leftovers accrued to the charity
+    pub fn get_charity_accrued(env: Env) -> u64 {
+        env.storage().instance().get(&CHARITY_ACCRUED).unwrap_or(0)
+    }
+
+    /// Move the configured transfer fee from the sender's unclaimed rewards to the charity accrual
+    /// The fee is capped at the sender's unclaimed balance, so a sender with no pending
+    /// rewards transfers for free; the global unclaimed total is reduced by the same amount
+    fn charge_transfer_fee(env: &Env, sender: &Address) {
+        let fee: u64 = env.storage().instance().get(&TRANSFER_FEE).unwrap_or(0);
+        if fee == 0 {
+            return;
+        }
+
+        let key = ("unclaimed", sender.clone());
+        let balance: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let charged = fee.min(balance);
+        if charged == 0 {
+            return;
+        }
+
+        env.storage().instance().set(&key, &(balance - charged));
+
+        let total: u64 = env.storage().instance().get(&TOTAL_UNCLAIMED).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&TOTAL_UNCLAIMED, &total.saturating_sub(charged));
+
+        let accrued: u64 = env.storage().instance().get(&CHARITY_ACCRUED).unwrap_or(0);
+        env.storage().instance().set(
+            &CHARITY_ACCRUED,
+            &accrued.checked_add(charged).expect("Overflow in charity accrual"),
+        );
+    }
+
+    /// Set the maximum number of transfers a waste can have (admin only)
+    /// A value of 0 disables the limit
+    pub fn set_max_transfers_per_waste(env: Env, admin: Address, max_transfers: u32) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&MAX_TRANSFERS, &max_transfers);
+    }
+
+    /// Get the maximum number of transfers a waste can have (0 = unlimited)
+    pub fn get_max_transfers_per_waste(env: Env) -> u32 {
+        env.storage().instance().get(&MAX_TRANSFERS).unwrap_or(0)
+    }
+
+    // ========== Incentive Configuration Functions ==========
+
+    /// Set the minimum reward_points allowed for incentives (admin only)
+    /// A value of 0 disables the check
+    pub fn set_min_incentive_reward(env: Env, admin: Address, min_reward: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&MIN_INC_REWARD, &min_reward);
+    }
+
+    /// Get the minimum reward_points allowed for incentives (0 = no minimum)
+    pub fn get_min_incentive_reward(env: Env) -> u64 {
+        env.storage().instance().get(&MIN_INC_REWARD).unwrap_or(0)
+    }
+
+    /// Panics if the reward is below the configured incentive minimum
+    fn require_min_incentive_reward(env: &Env, reward_points: u64) {
+        let min_reward: u64 = env.storage().instance().get(&MIN_INC_REWARD).unwrap_or(0);
+        if min_reward > 0 && reward_points < min_reward {
+            panic!("Reward below minimum");
+        }
+    }
+
+    // ========== Season Functions ==========
+
+    /// Start a new season (admin only)
+    /// Moves each participant's current-season points into `previous_season_points`
+    /// and zeroes the current-season totals; all-time `total_points` are kept
+    pub fn reset_season(env: Env, admin: Address) {
+        Self::only_admin(&env, &admin);
+
+        let mut participants_reset: u32 = 0;
+        for address in Self::get_participant_index(&env).iter() {
+            let key = ("stats", address);
+            if let Some(mut stats) = env.storage().instance().get::<_, RecyclingStats>(&key) {
+                stats.reset_season();
+                env.storage().instance().set(&key, &stats);
+                participants_reset += 1;
+            }
+        }
+
+        events::emit_season_reset(&env, participants_reset);
+    }
+
+    // ========== Waste Type Allowlist Functions ==========
+
+    /// Add a waste type to the accepted allowlist (admin only)
+    /// An empty allowlist accepts all waste types
+    pub fn add_allowed_type(env: Env, admin: Address, waste_type: WasteType) {
+        Self::only_admin(&env, &admin);
+
+        let mut allowed = Self::get_allowed_types(env.clone());
+        if !allowed.contains(waste_type) {
+            allowed.push_back(waste_type);
+            env.storage().instance().set(&ALLOWED_TYPES, &allowed);
+        }
+    }
+
+    /// Remove a waste type from the accepted allowlist (admin only)
+    pub fn remove_allowed_type(env: Env, admin: Address, waste_type: WasteType) {
+        Self::only_admin(&env, &admin);
+
+        let mut allowed = Self::get_allowed_types(env.clone());
+        if let Some(index) = allowed.first_index_of(waste_type) {
+            allowed.remove(index);
+            env.storage().instance().set(&ALLOWED_TYPES, &allowed);
+        }
+    }
+
+    /// Get the accepted waste types (empty = all types accepted)
+    pub fn get_allowed_types(env: Env) -> Vec<WasteType> {
+        env.storage()
+            .instance()
+            .get(&ALLOWED_TYPES)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Panics if the waste type is not accepted by the allowlist
+    fn require_waste_type_allowed(env: &Env, waste_type: WasteType) {
+        let allowed: Vec<WasteType> = env
+            .storage()
+            .instance()
+            .get(&ALLOWED_TYPES)
+            .unwrap_or(Vec::new(env));
+        if !allowed.is_empty() && !allowed.contains(waste_type) {
+            panic!("Waste type not accepted");
+        }
+    }
+
+    // ========== Storage Maintenance Functions ==========
+
+    /// Check that every waste ID in a participant's index exists and is owned by them (admin only)
+    /// Returns false if the index has drifted from the waste records
+    pub fn verify_index_consistency(env: Env, admin: Address, participant: Address) -> bool {
+        Self::only_admin(&env, &admin);
+
+        for waste_id in Self::get_participant_wastes_v2(env.clone(), participant.clone()).iter() {
+            let waste: Option<types::Waste> = env.storage().instance().get(&("waste_v2", waste_id));
+            match waste {
+                Some(waste) if waste.current_owner == participant => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Rebuild a participant's waste index from authoritative waste ownership (admin only)
+    /// Returns the rebuilt index
+    pub fn repair_participant_index(env: Env, admin: Address, participant: Address) -> Vec<u128> {
+        Self::only_admin(&env, &admin);
+
+        let mut index = Vec::new(&env);
+        for waste_id in 1..=Self::get_waste_count(&env) {
+            let waste: Option<types::Waste> =
+                env.storage().instance().get(&("waste_v2", waste_id as u128));
+            if let Some(waste) = waste {
+                if waste.current_owner == participant {
+                    index.push_back(waste_id as u128);
+                }
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", participant), &index);
+
+        index
+    }
+
+    /// Find active wastes whose current owner has no participant record (admin only)
+    pub fn find_orphaned_wastes(env: Env, admin: Address) -> Vec<u128> {
+        Self::only_admin(&env, &admin);
+
+        let mut orphaned = Vec::new(&env);
+        for waste_id in 1..=Self::get_waste_count(&env) {
+            let waste: Option<types::Waste> =
+                env.storage().instance().get(&("waste_v2", waste_id as u128));
+            if let Some(waste) = waste {
+                if waste.is_active
+                    && !env.storage().instance().has(&(waste.current_owner.clone(),))
+                {
+                    orphaned.push_back(waste_id as u128);
+                }
+            }
+        }
+
+        orphaned
+    }
+
+    /// Set how long, in seconds, an active waste may sit before it counts as stale (admin only)
+    /// A value of 0 disables expiry
+    pub fn set_waste_expiry(env: Env, admin: Address, expiry: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&WASTE_EXPIRY, &expiry);
+    }
+
+    /// Get how long, in seconds, an active waste may sit before it counts as stale (0 = never)
+    pub fn get_waste_expiry(env: Env) -> u64 {
+        env.storage().instance().get(&WASTE_EXPIRY).unwrap_or(0)
+    }
+
+    /// Deactivate up to `limit` active wastes older than the configured expiry (admin only)
+    /// Wastes are visited in ID order; returns the number deactivated
+    pub fn expire_stale_wastes(env: Env, admin: Address, limit: u32) -> u32 {
+        Self::only_admin(&env, &admin);
+
+        let expiry = Self::get_waste_expiry(env.clone());
+        if expiry == 0 {
+            return 0;
+        }
+
+        let now = env.ledger().timestamp();
+        let mut expired: u32 = 0;
+        for waste_id in 1..=Self::get_waste_count(&env) {
+            if expired >= limit {
+                break;
+            }
+
+            let key = ("waste_v2", waste_id as u128);
+            if let Some(mut waste) = env.storage().instance().get::<_, types::Waste>(&key) {
+                if waste.is_active && now.saturating_sub(waste.recycled_timestamp) > expiry {
+                    waste.deactivate();
+                    env.storage().instance().set(&key, &waste);
+                    Self::remove_from_confirmed_index(&env, waste_id as u128);
+                    expired += 1;
+                }
+            }
+        }
+
+        Self::remove_active_wastes(&env, expired);
+        expired
+    }
+
+    /// Deactivate up to `limit` active incentives whose expiry has passed (admin only)
+    /// Incentives are visited in ID order; returns the number deactivated
+    pub fn sweep_expired_incentives(env: Env, admin: Address, limit: u32) -> u32 {
+        Self::only_admin(&env, &admin);
+
+        let now = env.ledger().timestamp();
+        let mut swept: u32 = 0;
+        for incentive_id in 1..=Self::get_incentive_count(&env) {
+            if swept >= limit {
+                break;
+            }
+
+            if let Some(mut incentive) = Self::get_incentive(&env, incentive_id) {
+                if incentive.active && incentive.is_expired(now) {
+                    incentive.deactivate();
+                    Self::set_incentive(&env, incentive_id, &incentive);
+                    swept += 1;
+                }
+            }
+        }
+
+        swept
+    }
+
+    // ========== Registration Configuration Functions ==========
+
+    /// Set the maximum number of registered participants (admin only)
+    /// A value of 0 disables the cap
+    pub fn set_max_participants(env: Env, admin: Address, max_participants: u32) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&MAX_PARTICIPANTS, &max_participants);
+    }
+
+    /// Get the maximum number of registered participants (0 = unlimited)
+    pub fn get_max_participants(env: Env) -> u32 {
+        env.storage().instance().get(&MAX_PARTICIPANTS).unwrap_or(0)
+    }
+
+    // ========== Coordinate Configuration Functions ==========
+
+    /// Set the number of coordinate units per degree used for validation (admin only)
+    /// e.g. 1_000_000 for microdegrees (the default) or 1_000 for millidegrees
+    pub fn set_coordinate_scale(env: Env, admin: Address, scale: u64) {
+        Self::only_admin(&env, &admin);
+        if scale == 0 {
+            panic!("Coordinate scale must be positive");
+        }
+        env.storage().instance().set(&COORD_SCALE, &scale);
+    }
+
+    /// Get the number of coordinate units per degree used for validation
+    pub fn get_coordinate_scale(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&COORD_SCALE)
+            .unwrap_or(DEFAULT_COORDINATE_SCALE)
+    }
+
+    /// Validate coordinates against bounds derived from the configured scale
+    fn validate_scaled_coordinates(env: &Env, latitude: i128, longitude: i128) {
+        let scale = Self::get_coordinate_scale(env.clone());
+        validation::validate_coordinates(latitude, longitude, scale as i128);
+    }
+
     // ========== Participant Storage Functions ==========
 
     /// Store a participant record
@@ -395,6 +952,32 @@ impl ScavengerContract {
         env.storage().instance().set(&key, participant);
     }
 
+    /// Get the index of all registered participant addresses
+    fn get_participant_index(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&("participant_index",))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Add an address to the participant index if not already present
+    fn add_to_participant_index(env: &Env, address: &Address) {
+        let mut index = Self::get_participant_index(env);
+        if !index.contains(address) {
+            index.push_back(address.clone());
+            env.storage().instance().set(&("participant_index",), &index);
+        }
+    }
+
+    /// Remove an address from the participant index
+    fn remove_from_participant_index(env: &Env, address: &Address) {
+        let mut index = Self::get_participant_index(env);
+        if let Some(pos) = index.first_index_of(address) {
+            index.remove(pos);
+            env.storage().instance().set(&("participant_index",), &index);
+        }
+    }
+
     /// Check if a participant is registered
     pub fn is_participant_registered(env: Env, address: Address) -> bool {
         let key = (address,);
@@ -414,13 +997,18 @@ impl ScavengerContract {
         address.require_auth();
 
         // Validate coordinates
-        validation::validate_coordinates(latitude, longitude);
+        Self::validate_scaled_coordinates(&env, latitude, longitude);
 
         // Check if already registered
         if Self::is_participant_registered(env.clone(), address.clone()) {
             panic!("Participant already registered");
         }
 
+        let max_participants: u32 = env.storage().instance().get(&MAX_PARTICIPANTS).unwrap_or(0);
+        if max_participants > 0 && Self::get_participant_index(&env).len() >= max_participants {
+            panic!("Registration closed");
+        }
+
         let participant = Participant {
             address: address.clone(),
             role,
@@ -435,6 +1023,7 @@ impl ScavengerContract {
 
         // Store participant using helper function
         Self::set_participant(&env, &address, &participant);
+        Self::add_to_participant_index(&env, &address);
 
         // Emit event
         events::emit_participant_registered(
@@ -475,19 +1064,17 @@ impl ScavengerContract {
             // Update global total tokens if tokens were earned
             if tokens_earned > 0 {
                 Self::add_to_total_tokens(env, tokens_earned as u128);
+                Self::add_unclaimed_rewards(env, address, tokens_earned);
             }
         }
     }
 
-    /// Validate that a participant is registered before allowing restricted actions
-    fn require_registered(env: &Env, address: &Address) {
-        let key = (address.clone(),);
-        let participant: Option<Participant> = env.storage().instance().get(&key);
-
-        match participant {
-            Some(p) if p.is_registered => {}
-            Some(_) => panic!("Participant is not registered"),
-            None => panic!("Participant not found"),
+    /// Load a registered participant
+    /// Panics with "Participant not registered" if the address is unknown or deregistered
+    fn get_registered_participant(env: &Env, address: &Address) -> Participant {
+        match env.storage().instance().get::<_, Participant>(&(address.clone(),)) {
+            Some(p) if p.is_registered => p,
+            _ => panic!("Participant not registered"),
         }
     }
 
@@ -499,13 +1086,19 @@ impl ScavengerContract {
     ) {
         let transfers = Self::get_transfer_history(env.clone(), waste_id);
         
-        let collector_pct: u32 = env.storage().instance().get(&COLLECTOR_PCT).unwrap_or(5);
-        let owner_pct: u32 = env.storage().instance().get(&OWNER_PCT).unwrap_or(50);
+        let collector_pct: u32 = env
+            .storage()
+            .instance()
+            .get(&COLLECTOR_PCT)
+            .unwrap_or(DEFAULT_COLLECTOR_PCT);
+        let owner_pct: u32 = env.storage().instance().get(&OWNER_PCT).unwrap_or(DEFAULT_OWNER_PCT);
+        let submitter_pct: Option<u32> = env.storage().instance().get(&SUBMITTER_PCT);
         
         let collector_share = (total_reward * (collector_pct as u128)) / 100;
         let owner_share = (total_reward * (owner_pct as u128)) / 100;
         
         let mut total_distributed: u128 = 0;
+        let mut allocated_pct: u32 = owner_pct;
         
         // Iterate through transfer history and reward collectors
         for transfer in transfers.iter() {
@@ -513,8 +1106,10 @@ impl ScavengerContract {
             if let Some(p) = participant {
                 if matches!(p.role, ParticipantRole::Collector) {
                     total_distributed += collector_share;
+                    allocated_pct += collector_pct;
                     Self::update_participant_stats(env, &transfer.to, 0, collector_share as u64);
-                    events::emit_tokens_rewarded(env, &transfer.to, collector_share, waste_id);
+                    events::emit_reward_share(env, &transfer.to, collector_share, waste_id);
+                    events::emit_tokens_rewarded(env, &transfer.to, collector_share, waste_id, 0);
                 }
             }
         }
@@ -523,12 +1118,64 @@ impl ScavengerContract {
         if let Some(material) = Self::get_waste_internal(env, waste_id) {
             total_distributed += owner_share;
             Self::update_participant_stats(env, &material.submitter, 0, owner_share as u64);
-            events::emit_tokens_rewarded(env, &material.submitter, owner_share, waste_id);
+            events::emit_reward_share(env, &material.submitter, owner_share, waste_id);
+            events::emit_tokens_rewarded(env, &material.submitter, owner_share, waste_id, 0);
             
-            let recycler_amount = total_reward.saturating_sub(total_distributed);
+            // Without an explicit share the submitter is owed whatever percentage is left;
+            // collector shares are paid per hop, so never pay out more than what remains
+            let recycler_pct = submitter_pct.unwrap_or(100u32.saturating_sub(allocated_pct));
+            let mut recycler_amount = ((total_reward * (recycler_pct as u128)) / 100)
+                .min(total_reward.saturating_sub(total_distributed));
+
+            // Route the rounding leftover to the configured sink
+            let leftover = total_reward.saturating_sub(total_distributed + recycler_amount);
+            let sink = Self::get_rounding_sink(env.clone());
+            if sink == RoundingSink::Recycler {
+                recycler_amount += leftover;
+            }
+
             if recycler_amount > 0 {
                 Self::update_participant_stats(env, &material.submitter, 0, recycler_amount as u64);
-                events::emit_tokens_rewarded(env, &material.submitter, recycler_amount, waste_id);
+                events::emit_reward_share(env, &material.submitter, recycler_amount, waste_id);
+                events::emit_tokens_rewarded(env, &material.submitter, recycler_amount, waste_id, 0);
+            }
+
+            if sink != RoundingSink::Recycler && leftover > 0 {
+                Self::route_rounding_leftover(env, sink, leftover, waste_id);
+            }
+        }
+    }
+
+    /// Send a rounding leftover to a non-recycler sink
+    fn route_rounding_leftover(env: &Env, sink: RoundingSink, leftover: u128, waste_id: u64) {
+        match sink {
+            RoundingSink::Recycler => {}
+            RoundingSink::Charity => {
+                // Accrue even without a charity address so the leftover is never lost
+                let accrued: u64 = env.storage().instance().get(&CHARITY_ACCRUED).unwrap_or(0);
+                let leftover_points = u64::try_from(leftover).expect("Overflow in charity accrual");
+                env.storage().instance().set(
+                    &CHARITY_ACCRUED,
+                    &accrued.checked_add(leftover_points).expect("Overflow in charity accrual"),
+                );
+                if let Some(charity) = env.storage().instance().get::<_, Address>(&CHARITY) {
+                    events::emit_reward_share(env, &charity, leftover, waste_id);
+                    events::emit_tokens_rewarded(env, &charity, leftover, waste_id, 0);
+                }
+            }
+            RoundingSink::Treasury => {
+                let balance: u128 = env.storage().instance().get(&TREASURY_BALANCE).unwrap_or(0);
+                env.storage().instance().set(
+                    &TREASURY_BALANCE,
+                    &balance.checked_add(leftover).expect("Overflow in treasury balance"),
+                );
+            }
+            RoundingSink::Burn => {
+                let burned: u128 = env.storage().instance().get(&TOTAL_BURNED).unwrap_or(0);
+                env.storage().instance().set(
+                    &TOTAL_BURNED,
+                    &burned.checked_add(leftover).expect("Overflow in burned total"),
+                );
             }
         }
     }
@@ -558,6 +1205,11 @@ impl ScavengerContract {
         String::from_str(&env, waste_type.as_str())
     }
 
+    /// Get the display name of a waste type (e.g. "PAPER")
+    pub fn get_waste_type_name(env: Env, waste_type: WasteType) -> String {
+        Self::get_waste_type_string(env, waste_type)
+    }
+
     /// Convert participant role enum to a human-readable string.
     pub fn get_participant_role_string(env: Env, role: ParticipantRole) -> String {
         String::from_str(&env, role.as_str())
@@ -640,6 +1292,26 @@ impl ScavengerContract {
         Self::get_incentive_internal(env, incentive_id)
     }
 
+    /// Append a (timestamp, reward_points, total_budget) snapshot to an incentive's history
+    fn record_incentive_history(env: &Env, incentive: &Incentive) {
+        let key = ("incentive_history", incentive.id);
+        let mut history: Vec<(u64, u64, u64)> =
+            env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        history.push_back((
+            env.ledger().timestamp(),
+            incentive.reward_points,
+            incentive.total_budget,
+        ));
+        env.storage().instance().set(&key, &history);
+    }
+
+    /// Get the audit log of an incentive's reward/budget changes
+    /// Returns chronologically ordered (timestamp, reward_points, total_budget) snapshots
+    pub fn get_incentive_history(env: Env, incentive_id: u64) -> Vec<(u64, u64, u64)> {
+        let key = ("incentive_history", incentive_id);
+        env.storage().instance().get(&key).unwrap_or(Vec::new(&env))
+    }
+
     /// Check if an incentive record exists
     pub fn incentive_exists(env: Env, incentive_id: u64) -> bool {
         let key = ("incentive", incentive_id);
@@ -674,6 +1346,32 @@ impl ScavengerContract {
         env.storage().instance().set(&TOTAL_TOKENS, &new_total);
     }
 
+    /// Add to global total points awarded for verified materials
+    fn add_to_total_points(env: &Env, points: u64) {
+        let current: u64 = env.storage().instance().get(&TOTAL_POINTS).unwrap_or(0);
+        let new_total = current
+            .checked_add(points)
+            .expect("Overflow in total points");
+        env.storage().instance().set(&TOTAL_POINTS, &new_total);
+    }
+
+    /// Add newly created active wastes to the global active count
+    fn add_active_wastes(env: &Env, count: u32) {
+        let current: u32 = env.storage().instance().get(&TOTAL_ACTIVE_WASTES).unwrap_or(0);
+        let new_total = current
+            .checked_add(count)
+            .expect("Overflow in total active wastes");
+        env.storage().instance().set(&TOTAL_ACTIVE_WASTES, &new_total);
+    }
+
+    /// Remove deactivated wastes from the global active count
+    fn remove_active_wastes(env: &Env, count: u32) {
+        let current: u32 = env.storage().instance().get(&TOTAL_ACTIVE_WASTES).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&TOTAL_ACTIVE_WASTES, &current.saturating_sub(count));
+    }
+
     /// Calculate total weight for active waste entries in v2 storage.
     /// Iterates once across the waste ID range to keep reads linear and allocation-free.
     fn get_total_active_waste_weight(env: &Env) -> u64 {
@@ -704,6 +1402,26 @@ impl ScavengerContract {
         Self::get_incentive(&env, incentive_id)
     }
 
+    /// Get the remaining budget of an incentive
+    /// Returns None if the incentive doesn't exist
+    pub fn get_incentive_remaining_budget(env: Env, incentive_id: u64) -> Option<u64> {
+        Self::get_incentive(&env, incentive_id).map(|incentive| incentive.remaining_budget)
+    }
+
+    /// Get the most an incentive can currently pay per reward, capped by its remaining budget
+    /// Returns None if the incentive doesn't exist
+    pub fn get_effective_reward(env: Env, incentive_id: u64) -> Option<u64> {
+        Self::get_incentive(&env, incentive_id)
+            .map(|incentive| incentive.reward_points.min(incentive.remaining_budget))
+    }
+
+    /// Get how much of an incentive's budget has been paid out
+    /// Returns None if the incentive doesn't exist
+    pub fn get_incentive_budget_spent(env: Env, incentive_id: u64) -> Option<u64> {
+        Self::get_incentive(&env, incentive_id)
+            .map(|incentive| incentive.total_budget.saturating_sub(incentive.remaining_budget))
+    }
+
     /// Update incentive active status
     /// Only the rewarder can update their incentive
     pub fn update_incentive_status(env: Env, incentive_id: u64, is_active: bool) -> Incentive {
@@ -719,6 +1437,125 @@ impl ScavengerContract {
         incentive
     }
 
+    /// Set the expiry timestamp of an incentive (0 = never expires)
+    /// Only the rewarder can update their incentive
+    pub fn set_incentive_expiry(env: Env, incentive_id: u64, expires_at: u64) -> Incentive {
+        let mut incentive: Incentive =
+            Self::get_incentive(&env, incentive_id).expect("Incentive not found");
+
+        // Require auth from the rewarder
+        incentive.rewarder.require_auth();
+
+        incentive.expires_at = expires_at;
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        incentive
+    }
+
+    /// Set how an incentive's payout is computed (rewarder only)
+    pub fn set_incentive_mode(env: Env, incentive_id: u64, mode: IncentiveMode) -> Incentive {
+        let mut incentive: Incentive =
+            Self::get_incentive(&env, incentive_id).expect("Incentive not found");
+
+        // Require auth from the rewarder
+        incentive.rewarder.require_auth();
+
+        incentive.mode = mode;
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        incentive
+    }
+
+    /// Configure periodic budget renewal for an incentive (rewarder only)
+    /// The renewal clock starts from the time of this call
+    pub fn set_incentive_auto_renew(
+        env: Env,
+        incentive_id: u64,
+        renew_period: u64,
+        renew_amount: u64,
+    ) -> Incentive {
+        let mut incentive: Incentive =
+            Self::get_incentive(&env, incentive_id).expect("Incentive not found");
+
+        // Require auth from the rewarder
+        incentive.rewarder.require_auth();
+
+        if renew_period == 0 {
+            panic!("Renewal period must be greater than zero");
+        }
+
+        incentive.auto_renew = AutoRenew::On(IncentiveRenewal {
+            renew_period,
+            renew_amount,
+            last_renewed_at: env.ledger().timestamp(),
+        });
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        incentive
+    }
+
+    /// Renew an auto-renewing incentive whose period has elapsed
+    /// Tops up the remaining budget by `renew_amount` and starts the next period
+    pub fn renew_incentive(env: Env, incentive_id: u64) -> Incentive {
+        let mut incentive: Incentive =
+            Self::get_incentive(&env, incentive_id).expect("Incentive not found");
+
+        if incentive.auto_renew == AutoRenew::Off {
+            panic!("Incentive does not auto-renew");
+        }
+        if !incentive.is_renewal_due(env.ledger().timestamp()) {
+            panic!("Renewal period not elapsed");
+        }
+
+        incentive.renew();
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        incentive
+    }
+
+    /// Get the reward points an incentive pays per match
+    /// Returns None if the incentive doesn't exist
+    pub fn get_incentive_reward_points(env: Env, incentive_id: u64) -> Option<u64> {
+        Self::get_incentive(&env, incentive_id).map(|incentive| incentive.reward_points)
+    }
+
+    /// Get the manufacturer that created an incentive
+    /// Returns None if the incentive doesn't exist
+    pub fn get_incentive_manufacturer(env: Env, incentive_id: u64) -> Option<Address> {
+        Self::get_incentive(&env, incentive_id).map(|incentive| incentive.rewarder)
+    }
+
+    /// Get the number of wastes an incentive has paid out for
+    /// Returns None if the incentive doesn't exist
+    pub fn get_incentive_match_count(env: Env, incentive_id: u64) -> Option<u32> {
+        Self::get_incentive(&env, incentive_id).map(|incentive| incentive.matches)
+    }
+
+    /// Check whether an incentive can currently pay out
+    /// (active, not expired and with budget remaining)
+    /// Returns None if the incentive doesn't exist
+    pub fn is_incentive_active(env: Env, incentive_id: u64) -> Option<bool> {
+        let now = env.ledger().timestamp();
+        Self::get_incentive(&env, incentive_id).map(|incentive| incentive.is_available(now))
+    }
+
+    /// Get a comparable per-kg reward figure for an incentive
+    /// Flat incentives report their flat reward_points, per-kg incentives their per-kg rate
+    /// Returns None if the incentive doesn't exist
+    pub fn get_normalized_reward_per_kg(env: Env, incentive_id: u64) -> Option<u64> {
+        Self::get_incentive(&env, incentive_id).map(|incentive| match incentive.mode {
+            IncentiveMode::Flat => incentive.reward_points,
+            IncentiveMode::PerKg => incentive.reward_points,
+        })
+    }
+
+    /// Check whether an incentive has expired
+    /// Returns None if the incentive doesn't exist
+    pub fn is_incentive_expired(env: Env, incentive_id: u64) -> Option<bool> {
+        let incentive = Self::get_incentive(&env, incentive_id)?;
+        Some(incentive.is_expired(env.ledger().timestamp()))
+    }
+
     /// Update an existing incentive's reward and budget
     /// Only the rewarder can update their incentive
     /// Only active incentives can be updated
@@ -747,6 +1584,7 @@ impl ScavengerContract {
         if new_total_budget == 0 {
             panic!("Total budget must be greater than zero");
         }
+        Self::require_min_incentive_reward(&env, new_reward_points);
 
         // Calculate how much budget has been used
         let budget_used = incentive.total_budget - incentive.remaining_budget;
@@ -765,6 +1603,7 @@ impl ScavengerContract {
 
         // Step 6: Persist to storage
         Self::set_incentive(&env, incentive_id, &incentive);
+        Self::record_incentive_history(&env, &incentive);
 
         // Step 7: Emit event
         env.events().publish(
@@ -785,15 +1624,13 @@ impl ScavengerContract {
         let incentive: Incentive = Self::get_incentive(&env, incentive_id)
             .expect("Incentive not found");
 
-        // Check if incentive is active
-        if !incentive.active {
+        // Check if incentive is active and unexpired
+        if !incentive.is_available(env.ledger().timestamp()) {
             return 0;
         }
 
-        // Calculate reward: (weight in kg) * reward_points
-        let weight_kg = waste_amount / 1000;
-        let reward = weight_kg * incentive.reward_points;
-        
+        let reward = incentive.calculate_reward(waste_amount);
+
         // Cap at remaining budget
         if reward > incentive.remaining_budget {
             incentive.remaining_budget
@@ -809,10 +1646,11 @@ impl ScavengerContract {
     ) -> soroban_sdk::Vec<Incentive> {
         let mut results: soroban_sdk::Vec<Incentive> = soroban_sdk::Vec::new(&env);
         let count = Self::get_incentive_count(&env);
+        let now = env.ledger().timestamp();
 
         for i in 1..=count {
             if let Some(incentive) = Self::get_incentive(&env, i) {
-                if incentive.waste_type == waste_type && incentive.active {
+                if incentive.waste_type == waste_type && incentive.is_available(now) {
                     // Keep results sorted by reward_points descending.
                     let mut inserted = false;
                     for idx in 0..results.len() {
@@ -832,19 +1670,114 @@ impl ScavengerContract {
         results
     }
 
+    /// Get active incentives for a waste type with at least `min_budget` remaining
+    /// Results are sorted by reward_points descending
+    pub fn get_incentives_with_min_budget(
+        env: Env,
+        waste_type: WasteType,
+        min_budget: u64,
+    ) -> Vec<Incentive> {
+        let mut results = Vec::new(&env);
+
+        for incentive in Self::get_incentives_by_waste_type(env.clone(), waste_type).iter() {
+            if incentive.remaining_budget >= min_budget {
+                results.push_back(incentive);
+            }
+        }
+
+        results
+    }
+
     /// Get all incentives for a specific waste type (alias)
     pub fn get_incentives(env: Env, waste_type: WasteType) -> soroban_sdk::Vec<Incentive> {
         Self::get_incentives_by_waste_type(env, waste_type)
     }
 
+    /// Get active incentives for several waste types at once
+    /// Results are unioned across types and de-duplicated by incentive ID
+    pub fn get_incentives_by_types(env: Env, types: Vec<WasteType>) -> Vec<Incentive> {
+        let mut results: Vec<Incentive> = Vec::new(&env);
+
+        for waste_type in types.iter() {
+            for incentive in Self::get_incentives_by_waste_type(env.clone(), waste_type).iter() {
+                if !results.iter().any(|existing| existing.id == incentive.id) {
+                    results.push_back(incentive);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get the IDs of incentives created for a waste type
+    fn get_general_incentives(env: &Env, waste_type: WasteType) -> Vec<u64> {
+        // Check typed storage first
+        if let Some(ids) = env
+            .storage()
+            .instance()
+            .get::<_, Vec<u64>>(&StorageKey::GeneralIncentives(waste_type))
+        {
+            return ids;
+        }
+
+        // Fall back to the tuple key used before StorageKey
+        env.storage()
+            .instance()
+            .get(&("general_incentives", waste_type))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Check whether any incentive is currently available for a waste type
+    /// Only active, unexpired incentives with remaining budget are considered
+    pub fn has_active_incentive_for_type(env: Env, waste_type: WasteType) -> bool {
+        let incentive_ids = Self::get_general_incentives(&env, waste_type);
+        let now = env.ledger().timestamp();
+
+        incentive_ids.iter().any(|incentive_id| {
+            Self::get_incentive_internal(&env, incentive_id)
+                .map(|incentive| incentive.is_available(now))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Get the available incentive for a waste type with the highest reward_points
+    /// Ties are broken by lowest incentive ID; returns None if no incentive is available
+    pub fn get_best_incentive_for_type(env: Env, waste_type: WasteType) -> Option<Incentive> {
+        let incentive_ids = Self::get_general_incentives(&env, waste_type);
+        let now = env.ledger().timestamp();
+
+        let mut best: Option<Incentive> = None;
+        for incentive_id in incentive_ids.iter() {
+            if let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) {
+                if !incentive.is_available(now) {
+                    continue;
+                }
+                let is_better = match &best {
+                    Some(current) => {
+                        incentive.reward_points > current.reward_points
+                            || (incentive.reward_points == current.reward_points
+                                && incentive.id < current.id)
+                    }
+                    None => true,
+                };
+                if is_better {
+                    best = Some(incentive);
+                }
+            }
+        }
+
+        best
+    }
+
     /// Get all active incentives
     pub fn get_active_incentives(env: Env) -> soroban_sdk::Vec<Incentive> {
         let mut results = soroban_sdk::Vec::new(&env);
         let count = Self::get_incentive_count(&env);
+        let now = env.ledger().timestamp();
 
         for i in 1..=count {
             if let Some(incentive) = Self::get_incentive(&env, i) {
-                if incentive.active {
+                if incentive.is_available(now) {
                     results.push_back(incentive);
                 }
             }
@@ -853,12 +1786,44 @@ impl ScavengerContract {
         results
     }
 
+    /// Get every incentive record, including inactive ones (admin only)
+    pub fn get_all_incentives(env: Env, admin: Address) -> Vec<Incentive> {
+        Self::only_admin(&env, &admin);
+
+        let mut results = Vec::new(&env);
+        let count = Self::get_incentive_count(&env);
+
+        for i in 1..=count {
+            if let Some(incentive) = Self::get_incentive(&env, i) {
+                results.push_back(incentive);
+            }
+        }
+
+        results
+    }
+
     /// Get participant information
     pub fn get_participant(env: Env, address: Address) -> Option<Participant> {
         let key = (address,);
         env.storage().instance().get(&key)
     }
 
+    /// Get only the role of a participant
+    /// Returns None if the participant is not registered
+    pub fn get_role(env: Env, address: Address) -> Option<ParticipantRole> {
+        Self::get_participant(env, address).map(|participant| participant.role)
+    }
+
+    /// Get the token balance earned by a participant
+    /// Returns 0 if the participant is not registered
+    pub fn get_participant_tokens(env: Env, address: Address) -> u64 {
+        match Self::get_participant(env, address) {
+            Some(participant) => u64::try_from(participant.total_tokens_earned)
+                .expect("Token balance exceeds u64 range"),
+            None => 0,
+        }
+    }
+
     /// Get participant information with current statistics
     /// Returns participant details along with their recycling statistics
     /// Returns None if participant is not registered
@@ -870,26 +1835,41 @@ impl ScavengerContract {
         Some(ParticipantInfo { participant, stats })
     }
 
+    /// Get participant info for several addresses at once
+    /// Results are positional; unregistered addresses yield None
+    pub fn get_participant_info_batch(
+        env: Env,
+        addresses: Vec<Address>,
+    ) -> Vec<Option<ParticipantInfo>> {
+        let mut results = Vec::new(&env);
+        for address in addresses.iter() {
+            results.push_back(Self::get_participant_info(env.clone(), address));
+        }
+        results
+    }
+
     /// Update participant role
     /// Preserves registration timestamp and other data
     pub fn update_role(env: Env, address: Address, new_role: ParticipantRole) -> Participant {
         address.require_auth();
 
-        let mut participant: Participant =
-            Self::get_participant(env.clone(), address.clone()).expect("Participant not found");
-
-        // Validate participant is registered
-        if !participant.is_registered {
-            panic!("Participant is not registered");
-        }
+        let mut participant = Self::get_registered_participant(&env, &address);
 
+        let old_role = participant.role;
         participant.role = new_role;
         Self::set_participant(&env, &address, &participant);
 
+        if old_role != new_role {
+            events::emit_role_changed(&env, &address, old_role, new_role);
+        }
+
         participant
     }
 
-    /// Deregister a participant (sets is_registered to false)
+    /// Deregister a participant
+    /// Removes the participant record and index entry; recycling stats and
+    /// waste records are preserved for audit
+    /// Panics if the participant still owns active wastes
     pub fn deregister_participant(env: Env, address: Address) -> Participant {
         address.require_auth();
 
@@ -900,9 +1880,22 @@ impl ScavengerContract {
             .get(&key)
             .expect("Participant not found");
 
-        participant.is_registered = false;
-        env.storage().instance().set(&key, &participant);
+        for waste_id in Self::get_participant_wastes_v2(env.clone(), address.clone()).iter() {
+            if let Some(waste) = env
+                .storage()
+                .instance()
+                .get::<_, types::Waste>(&("waste_v2", waste_id))
+            {
+                if waste.is_active {
+                    panic!("Participant still owns active wastes");
+                }
+            }
+        }
 
+        env.storage().instance().remove(&key);
+        Self::remove_from_participant_index(&env, &address);
+
+        participant.is_registered = false;
         participant
     }
 
@@ -916,16 +1909,7 @@ impl ScavengerContract {
         address.require_auth();
 
         let key = (address.clone(),);
-        let mut participant: Participant = env
-            .storage()
-            .instance()
-            .get(&key)
-            .expect("Participant not found");
-
-        // Validate participant is registered
-        if !participant.is_registered {
-            panic!("Participant is not registered");
-        }
+        let mut participant = Self::get_registered_participant(&env, &address);
 
         participant.latitude = latitude;
         participant.longitude = longitude;
@@ -957,6 +1941,90 @@ impl ScavengerContract {
         Self::get_transfer_history(env, waste_id)
     }
 
+    /// Get a single transfer from a waste's history by its position
+    /// Returns None if the index is out of range
+    pub fn get_transfer(env: Env, waste_id: u64, index: u32) -> Option<WasteTransfer> {
+        Self::get_transfer_history(env, waste_id).get(index)
+    }
+
+    /// Get a page of the transfer history for a waste
+    /// Returns up to `limit` transfers starting at `offset`, empty past the end
+    pub fn get_transfer_history_paged(
+        env: Env,
+        waste_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<WasteTransfer> {
+        let history = Self::get_transfer_history(env.clone(), waste_id);
+        if offset >= history.len() {
+            return Vec::new(&env);
+        }
+
+        let end = offset.saturating_add(limit).min(history.len());
+        history.slice(offset..end)
+    }
+
+    /// Get the timestamp of the most recent transfer of a waste
+    /// Returns None if the waste has never been transferred
+    pub fn get_last_transfer_timestamp(env: Env, waste_id: u64) -> Option<u64> {
+        Self::get_transfer_history(env, waste_id)
+            .last()
+            .map(|transfer| transfer.transferred_at)
+    }
+
+    /// Get every address that has held a waste: its original owner followed by each
+    /// sender and recipient in its transfer history, without duplicates
+    pub fn get_waste_participants(env: Env, waste_id: u64) -> Vec<Address> {
+        let mut participants = Vec::new(&env);
+
+        let original_owner = match Self::get_waste_v2(env.clone(), waste_id as u128) {
+            Some(waste) => Some(waste.submitter),
+            None => Self::get_waste_internal(&env, waste_id).map(|material| material.submitter),
+        };
+        if let Some(owner) = original_owner {
+            participants.push_back(owner);
+        }
+
+        for transfer in Self::get_transfer_history(env.clone(), waste_id).iter() {
+            for address in [transfer.from, transfer.to] {
+                if !participants.contains(&address) {
+                    participants.push_back(address);
+                }
+            }
+        }
+
+        participants
+    }
+
+    /// Get a page of the transfers an address took part in, as sender or recipient
+    /// Returns up to `limit` transfers starting at `offset`, in the order they happened
+    pub fn get_address_transfers_paged(
+        env: Env,
+        address: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<WasteTransfer> {
+        let index: Vec<(u64, u32)> = env
+            .storage()
+            .instance()
+            .get(&("address_transfers", address))
+            .unwrap_or(Vec::new(&env));
+
+        let mut results = Vec::new(&env);
+        if offset >= index.len() {
+            return results;
+        }
+
+        let end = offset.saturating_add(limit).min(index.len());
+        for (waste_id, position) in index.slice(offset..end).iter() {
+            if let Some(transfer) = Self::get_transfer_history(env.clone(), waste_id).get(position) {
+                results.push_back(transfer);
+            }
+        }
+
+        results
+    }
+
     /// Get transfer history for a waste (v2 - uses u128 waste_id)
     /// Returns chronologically ordered list of transfers for new waste system
     pub fn get_waste_transfer_history_v2(env: Env, waste_id: u128) -> Vec<WasteTransfer> {
@@ -973,8 +2041,8 @@ impl ScavengerContract {
 
         let transfer = WasteTransfer::new(
             waste_id as u128,
-            from,
-            to,
+            from.clone(),
+            to.clone(),
             env.ledger().timestamp(),
             0,
             0,
@@ -983,6 +2051,41 @@ impl ScavengerContract {
 
         history.push_back(transfer);
         env.storage().instance().set(&key, &history);
+        Self::index_address_transfer(env, waste_id, history.len() - 1, &from, &to);
+    }
+
+    /// Record a transfer position against both parties' address-transfer index
+    fn index_address_transfer(env: &Env, waste_id: u64, position: u32, from: &Address, to: &Address) {
+        for address in [from, to] {
+            let key = ("address_transfers", address.clone());
+            let mut index: Vec<(u64, u32)> =
+                env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+            index.push_back((waste_id, position));
+            env.storage().instance().set(&key, &index);
+        }
+    }
+
+    /// Count a transfer as sent by `from` and received by `to`
+    fn record_transfer_counts(env: &Env, from: &Address, to: &Address) {
+        let from_key = ("transfer_counts", from.clone());
+        let (sent, received): (u32, u32) = env.storage().instance().get(&from_key).unwrap_or((0, 0));
+        env.storage()
+            .instance()
+            .set(&from_key, &(sent.saturating_add(1), received));
+
+        let to_key = ("transfer_counts", to.clone());
+        let (sent, received): (u32, u32) = env.storage().instance().get(&to_key).unwrap_or((0, 0));
+        env.storage()
+            .instance()
+            .set(&to_key, &(sent, received.saturating_add(1)));
+    }
+
+    /// Get how many transfers a participant has sent and received, as (sent, received)
+    pub fn get_participant_transfer_counts(env: Env, address: Address) -> (u32, u32) {
+        env.storage()
+            .instance()
+            .get(&("transfer_counts", address))
+            .unwrap_or((0, 0))
     }
 
     /// Transfer waste ownership from one participants to another
@@ -1069,14 +2172,34 @@ impl ScavengerContract {
         submitter: Address,
         description: String,
     ) -> Material {
-        // Validate submitter is registered
-        Self::only_registered(&env, &submitter);
-
-        // Get next waste ID using the new storage system
-        let waste_id = Self::next_waste_id(&env);
+        Self::submit_material_with_location(env, waste_type, weight, submitter, description, 0, 0)
+    }
 
-        // Create material
-        let material = Material::new(
+    /// Submit a new material for recycling with location data
+    pub fn submit_material_with_location(
+        env: Env,
+        waste_type: WasteType,
+        weight: u64,
+        submitter: Address,
+        description: String,
+        latitude: i128,
+        longitude: i128,
+    ) -> Material {
+        // Validate submitter is registered
+        Self::only_registered(&env, &submitter);
+
+        // Validate coordinates
+        Self::validate_scaled_coordinates(&env, latitude, longitude);
+
+        Self::require_waste_type_allowed(&env, waste_type);
+
+        let weight = Self::to_grams(&env, weight);
+
+        // Get next waste ID using the new storage system
+        let waste_id = Self::next_waste_id(&env);
+
+        // Create material
+        let mut material = Material::new(
             waste_id,
             waste_type,
             weight,
@@ -1084,9 +2207,11 @@ impl ScavengerContract {
             env.ledger().timestamp(),
             description,
         );
+        material.update_location(latitude, longitude);
 
         // Store waste using the new storage systems
         Self::set_waste(&env, waste_id, &material);
+        Self::record_submission_history(&env, &submitter, waste_id);
 
         // Update stats
         let mut stats: RecyclingStats = env
@@ -1121,6 +2246,12 @@ impl ScavengerContract {
         // Validate recycler is registered
         Self::only_registered(&env, &recycler);
 
+        Self::require_waste_type_allowed(&env, waste_type);
+
+        let weight = weight
+            .checked_mul(Self::get_weight_unit(env.clone()).grams_per_unit() as u128)
+            .expect("Overflow in weight conversion");
+
         let waste_id = Self::next_waste_id(&env) as u128;
         let timestamp = env.ledger().timestamp();
 
@@ -1140,6 +2271,8 @@ impl ScavengerContract {
         env.storage()
             .instance()
             .set(&("waste_v2", waste_id), &waste);
+        Self::add_to_waste_type_index(&env, waste_type, waste_id);
+        Self::add_active_wastes(&env, 1);
 
         let mut waste_list: Vec<u128> = env
             .storage()
@@ -1164,6 +2297,12 @@ impl ScavengerContract {
         env.storage().instance().get(&("waste_v2", waste_id))
     }
 
+    /// Get the original submitter and current owner of a waste (v2 API)
+    /// Returns None if the waste doesn't exist
+    pub fn get_submitter_and_owner(env: Env, waste_id: u128) -> Option<(Address, Address)> {
+        Self::get_waste_v2(env, waste_id).map(|waste| (waste.submitter, waste.current_owner))
+    }
+
     /// Get all waste IDs owned by a participant (v2 API)
     pub fn get_participant_wastes_v2(env: Env, participant: Address) -> Vec<u128> {
         env.storage()
@@ -1172,6 +2311,134 @@ impl ScavengerContract {
             .unwrap_or(Vec::new(&env))
     }
 
+    /// Get the IDs of an owner's wastes filtered by whether they are active (v2 API)
+    pub fn get_wastes_by_status(env: Env, owner: Address, active: bool) -> Vec<u128> {
+        let mut result = Vec::new(&env);
+        for waste_id in Self::get_participant_wastes_v2(env.clone(), owner).iter() {
+            if let Some(waste) = Self::get_waste_v2(env.clone(), waste_id) {
+                if waste.is_active == active {
+                    result.push_back(waste_id);
+                }
+            }
+        }
+        result
+    }
+
+    /// Get the full waste records owned by a participant (v2 API)
+    /// IDs whose records are missing are skipped
+    pub fn get_participant_wastes_full(env: Env, participant: Address) -> Vec<types::Waste> {
+        let mut wastes = Vec::new(&env);
+        for waste_id in Self::get_participant_wastes_v2(env.clone(), participant).iter() {
+            if let Some(waste) = Self::get_waste_v2(env.clone(), waste_id) {
+                wastes.push_back(waste);
+            }
+        }
+        wastes
+    }
+
+    /// Get waste IDs owned by a participant filtered by waste type (v2 API)
+    pub fn get_wastes_by_owner_and_type(
+        env: Env,
+        owner: Address,
+        waste_type: WasteType,
+    ) -> Vec<u128> {
+        let mut results = Vec::new(&env);
+
+        for waste_id in Self::get_participant_wastes_v2(env.clone(), owner).iter() {
+            if let Some(waste) = env
+                .storage()
+                .instance()
+                .get::<_, types::Waste>(&("waste_v2", waste_id))
+            {
+                if waste.waste_type == waste_type {
+                    results.push_back(waste_id);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get the owner's active wastes that have not been confirmed yet (v2 API)
+    pub fn get_wastes_awaiting_confirmation(env: Env, owner: Address) -> Vec<u128> {
+        let mut results = Vec::new(&env);
+
+        for waste_id in Self::get_participant_wastes_v2(env.clone(), owner).iter() {
+            if let Some(waste) = env
+                .storage()
+                .instance()
+                .get::<_, types::Waste>(&("waste_v2", waste_id))
+            {
+                if waste.is_active && !waste.is_confirmed {
+                    results.push_back(waste_id);
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Get the IDs of all wastes registered with a waste type (v2 API)
+    pub fn get_wastes_by_type(env: Env, waste_type: WasteType) -> Vec<u128> {
+        env.storage()
+            .instance()
+            .get(&StorageKey::WasteTypeIndex(waste_type))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Append a waste ID to the index for its waste type
+    fn add_to_waste_type_index(env: &Env, waste_type: WasteType, waste_id: u128) {
+        let key = StorageKey::WasteTypeIndex(waste_type);
+        let mut index: Vec<u128> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        index.push_back(waste_id);
+        env.storage().instance().set(&key, &index);
+    }
+
+    /// Authorize `delegate` to transfer the owner's wastes via `transfer_waste_v2`
+    pub fn add_transfer_delegate(env: Env, owner: Address, delegate: Address) {
+        owner.require_auth();
+
+        if owner == delegate {
+            panic!("Cannot delegate to self");
+        }
+
+        let mut delegates = Self::get_transfer_delegates(&env, &owner);
+        if delegates.contains(&delegate) {
+            panic!("Delegate already added");
+        }
+
+        delegates.push_back(delegate);
+        env.storage()
+            .instance()
+            .set(&("transfer_delegates", owner), &delegates);
+    }
+
+    /// Revoke a delegate's authority to transfer the owner's wastes
+    pub fn remove_transfer_delegate(env: Env, owner: Address, delegate: Address) {
+        owner.require_auth();
+
+        let mut delegates = Self::get_transfer_delegates(&env, &owner);
+        let pos = delegates.first_index_of(&delegate).expect("Delegate not found");
+
+        delegates.remove(pos);
+        env.storage()
+            .instance()
+            .set(&("transfer_delegates", owner), &delegates);
+    }
+
+    /// Check if an address may transfer the owner's wastes on their behalf
+    pub fn is_transfer_delegate(env: Env, owner: Address, delegate: Address) -> bool {
+        Self::get_transfer_delegates(&env, &owner).contains(&delegate)
+    }
+
+    /// Get the addresses authorized to transfer an owner's wastes
+    fn get_transfer_delegates(env: &Env, owner: &Address) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&("transfer_delegates", owner.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
     /// Transfer waste between participants with location tracking
     pub fn transfer_waste_v2(
         env: Env,
@@ -1181,8 +2448,9 @@ impl ScavengerContract {
         latitude: i128,
         longitude: i128,
     ) -> WasteTransfer {
-        // Access control check - verify caller owns the waste
-        Self::only_waste_owner(&env, &from, waste_id);
+        // Access control check - verify caller owns the waste or is the owner's delegate;
+        // the transfer is always made on the owner's behalf
+        let from = Self::only_waste_owner_or_delegate(&env, &from, waste_id);
 
         let mut waste: types::Waste = env
             .storage()
@@ -1191,7 +2459,14 @@ impl ScavengerContract {
             .expect("Waste item not found");
 
         if !waste.is_active {
-            panic!("Cannot transfer deactivated waste");
+            panic!("Cannot transfer inactive waste");
+        }
+
+        let max_transfers: u32 = env.storage().instance().get(&MAX_TRANSFERS).unwrap_or(0);
+        if max_transfers > 0
+            && Self::get_waste_transfer_history_v2(env.clone(), waste_id).len() >= max_transfers
+        {
+            panic!("Transfer limit reached");
         }
 
         if !Self::is_valid_transfer(env.clone(), from.clone(), to.clone()) {
@@ -1248,6 +2523,10 @@ impl ScavengerContract {
         env.storage()
             .instance()
             .set(&("transfer_history", waste_id), &history);
+        Self::index_address_transfer(&env, waste_id as u64, history.len() - 1, &from, &to);
+        Self::record_transfer_counts(&env, &from, &to);
+
+        Self::charge_transfer_fee(&env, &from);
 
         env.events().publish(
             (soroban_sdk::symbol_short!("transfer"), waste_id),
@@ -1257,6 +2536,68 @@ impl ScavengerContract {
         transfer
     }
 
+    /// Transfer waste and have the recipient implicitly confirm it on acceptance
+    /// Requires auth from the sender; the recipient is recorded as confirmer
+    pub fn transfer_and_confirm(
+        env: Env,
+        waste_id: u128,
+        from: Address,
+        to: Address,
+        latitude: i128,
+        longitude: i128,
+    ) -> types::Waste {
+        Self::transfer_waste_v2(env.clone(), waste_id, from, to.clone(), latitude, longitude);
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste item not found");
+
+        waste.confirm(to.clone());
+        env.storage()
+            .instance()
+            .set(&("waste_v2", waste_id), &waste);
+        Self::add_to_confirmed_index(&env, waste_id);
+
+        events::emit_waste_confirmed(&env, waste_id, &to);
+
+        waste
+    }
+
+    /// Transfer multiple wastes between the same participants
+    /// Inactive wastes are skipped; returns the transfers that were made
+    pub fn transfer_wastes_batch(
+        env: Env,
+        waste_ids: Vec<u128>,
+        from: Address,
+        to: Address,
+        latitude: i128,
+        longitude: i128,
+    ) -> Vec<WasteTransfer> {
+        let mut results = Vec::new(&env);
+
+        for waste_id in waste_ids.iter() {
+            let waste: Option<types::Waste> = env.storage().instance().get(&("waste_v2", waste_id));
+            if let Some(waste) = waste {
+                if !waste.is_active {
+                    continue;
+                }
+            }
+
+            results.push_back(Self::transfer_waste_v2(
+                env.clone(),
+                waste_id,
+                from.clone(),
+                to.clone(),
+                latitude,
+                longitude,
+            ));
+        }
+
+        results
+    }
+
     /// Transfer aggregated waste from collector to manufacturer
     pub fn transfer_collected_waste(
         env: Env,
@@ -1294,7 +2635,7 @@ impl ScavengerContract {
         let waste_id = Self::next_waste_id(&env) as u128;
         let timestamp = env.ledger().timestamp();
 
-        let waste = types::Waste::new(
+        let mut waste = types::Waste::new(
             waste_id,
             waste_type,
             0,
@@ -1306,10 +2647,13 @@ impl ScavengerContract {
             false,
             manufacturer.clone(),
         );
+        waste.submitter = collector.clone();
 
         env.storage()
             .instance()
             .set(&("waste_v2", waste_id), &waste);
+        Self::add_to_waste_type_index(&env, waste_type, waste_id);
+        Self::add_active_wastes(&env, 1);
 
         let mut manufacturer_list: Vec<u128> = env
             .storage()
@@ -1341,6 +2685,7 @@ impl ScavengerContract {
         env.storage()
             .instance()
             .set(&("transfer_history", waste_id), &history);
+        Self::index_address_transfer(&env, waste_id as u64, history.len() - 1, &collector, &manufacturer);
 
         env.events().publish(
             (soroban_sdk::symbol_short!("bulk_xfr"), waste_id),
@@ -1360,28 +2705,93 @@ impl ScavengerContract {
             .get(&("waste_v2", waste_id))
             .expect("Waste not found");
 
-        if !waste.is_active {
-            panic!("Cannot confirm deactivated waste");
-        }
-
-        if waste.current_owner == confirmer {
-            panic!("Owner cannot confirm own waste");
-        }
-
-        if waste.is_confirmed {
-            panic!("Waste already confirmed");
+        if let Some(reason) = Self::confirmation_error(&env, &waste, &confirmer) {
+            panic!("{}", reason);
         }
 
         waste.confirm(confirmer.clone());
         env.storage()
             .instance()
             .set(&("waste_v2", waste_id), &waste);
+        Self::add_to_confirmed_index(&env, waste_id);
 
         events::emit_waste_confirmed(&env, waste_id, &confirmer);
 
         waste
     }
 
+    /// Confirm multiple wastes in one call
+    /// Wastes that are missing or fail the third-party rule are skipped
+    /// Returns the number of wastes newly confirmed
+    pub fn confirm_wastes_batch(env: Env, waste_ids: Vec<u128>, confirmer: Address) -> u32 {
+        confirmer.require_auth();
+
+        let mut confirmed = 0u32;
+        for waste_id in waste_ids.iter() {
+            let waste: Option<types::Waste> = env.storage().instance().get(&("waste_v2", waste_id));
+            let mut waste = match waste {
+                Some(w) => w,
+                None => continue,
+            };
+            if Self::confirmation_error(&env, &waste, &confirmer).is_some() {
+                continue;
+            }
+
+            waste.confirm(confirmer.clone());
+            env.storage()
+                .instance()
+                .set(&("waste_v2", waste_id), &waste);
+            Self::add_to_confirmed_index(&env, waste_id);
+
+            events::emit_waste_confirmed(&env, waste_id, &confirmer);
+            confirmed += 1;
+        }
+
+        confirmed
+    }
+
+    /// Check whether `confirmer` may confirm `waste`
+    /// Returns the reason confirmation is not allowed, if any
+    fn confirmation_error(
+        env: &Env,
+        waste: &types::Waste,
+        confirmer: &Address,
+    ) -> Option<&'static str> {
+        if !waste.is_active {
+            return Some("Cannot confirm deactivated waste");
+        }
+
+        if waste.current_owner == *confirmer {
+            return Some("Owner cannot confirm own waste");
+        }
+
+        // Confirmation must come from a registered participant in a different role
+        let confirmer_participant = match env
+            .storage()
+            .instance()
+            .get::<_, Participant>(&(confirmer.clone(),))
+            .filter(|p| p.is_registered)
+        {
+            Some(p) => p,
+            None => return Some("Confirmer must be a third party"),
+        };
+        if let Some(owner) = env
+            .storage()
+            .instance()
+            .get::<_, Participant>(&(waste.current_owner.clone(),))
+        {
+            if owner.role == confirmer_participant.role {
+                return Some("Confirmer must be a third party");
+            }
+        }
+
+        if waste.is_confirmed {
+            return Some("Waste already confirmed");
+        }
+
+        None
+    }
+
     /// Reset waste confirmation status
     /// Only the waste owner can reset the confirmation
     pub fn reset_waste_confirmation(
@@ -1406,6 +2816,7 @@ impl ScavengerContract {
         env.storage()
             .instance()
             .set(&("waste_v2", waste_id), &waste);
+        Self::remove_from_confirmed_index(&env, waste_id);
 
         env.events().publish(
             (soroban_sdk::symbol_short!("reset"), waste_id),
@@ -1415,6 +2826,90 @@ impl ScavengerContract {
         waste
     }
 
+    /// Get the IDs of all currently confirmed wastes (v2 API)
+    pub fn get_confirmed_wastes(env: Env) -> Vec<u128> {
+        env.storage()
+            .instance()
+            .get(&("confirmed_wastes",))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Add a waste ID to the confirmed wastes index if not already present
+    fn add_to_confirmed_index(env: &Env, waste_id: u128) {
+        let mut index = Self::get_confirmed_wastes(env.clone());
+        if !index.contains(waste_id) {
+            index.push_back(waste_id);
+            env.storage().instance().set(&("confirmed_wastes",), &index);
+        }
+    }
+
+    /// Remove a waste ID from the confirmed wastes index
+    fn remove_from_confirmed_index(env: &Env, waste_id: u128) {
+        let mut index = Self::get_confirmed_wastes(env.clone());
+        if let Some(pos) = index.first_index_of(waste_id) {
+            index.remove(pos);
+            env.storage().instance().set(&("confirmed_wastes",), &index);
+        }
+    }
+
+    /// Update a waste's coordinates without changing ownership (owner only)
+    /// No transfer is recorded
+    pub fn update_waste_location(
+        env: Env,
+        waste_id: u128,
+        owner: Address,
+        latitude: i128,
+        longitude: i128,
+    ) -> types::Waste {
+        Self::only_waste_owner(&env, &owner, waste_id);
+        Self::validate_scaled_coordinates(&env, latitude, longitude);
+
+        let mut waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste item not found");
+
+        if !waste.is_active {
+            panic!("Cannot relocate inactive waste");
+        }
+
+        waste.latitude = latitude;
+        waste.longitude = longitude;
+        env.storage()
+            .instance()
+            .set(&("waste_v2", waste_id), &waste);
+
+        events::emit_waste_relocated(&env, waste_id, latitude, longitude);
+
+        waste
+    }
+
+    /// Get a waste's current (latitude, longitude)
+    /// Returns None if the waste does not exist
+    pub fn get_waste_location(env: Env, waste_id: u128) -> Option<(i128, i128)> {
+        env.storage()
+            .instance()
+            .get::<_, types::Waste>(&("waste_v2", waste_id))
+            .map(|waste| (waste.latitude, waste.longitude))
+    }
+
+    /// Get a waste's status as a single string
+    /// "inactive" once deactivated, otherwise "confirmed" or "active" depending on
+    /// whether it has been confirmed; returns None if the waste does not exist
+    pub fn get_waste_status(env: Env, waste_id: u128) -> Option<String> {
+        Self::get_waste_v2(env.clone(), waste_id).map(|waste| {
+            let status = if !waste.is_active {
+                "inactive"
+            } else if waste.is_confirmed {
+                "confirmed"
+            } else {
+                "active"
+            };
+            String::from_str(&env, status)
+        })
+    }
+
     /// Deactivate a waste record (admin only)
     /// Deactivated waste cannot be queried or reactivated
     pub fn deactivate_waste(
@@ -1438,6 +2933,8 @@ impl ScavengerContract {
         env.storage()
             .instance()
             .set(&("waste_v2", waste_id), &waste);
+        Self::remove_active_wastes(&env, 1);
+        Self::remove_from_confirmed_index(&env, waste_id);
 
         env.events().publish(
             (soroban_sdk::symbol_short!("deactive"), waste_id),
@@ -1447,44 +2944,146 @@ impl ScavengerContract {
         waste
     }
 
-    /// Batch submit multiple materials for recycling
-    /// More efficient than individual submissions
-    pub fn submit_materials_batch(
+    /// Set the maximum number of pieces a waste can be split into (admin only)
+    pub fn set_max_splits(env: Env, admin: Address, max_splits: u32) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&MAX_SPLITS, &max_splits);
+    }
+
+    /// Get the maximum number of pieces a waste can be split into
+    pub fn get_max_splits(env: Env) -> u32 {
+        env.storage().instance().get(&MAX_SPLITS).unwrap_or(DEFAULT_MAX_SPLITS)
+    }
+
+    /// Split a waste into several new wastes with the given weights
+    /// The weights must be nonzero and sum to the original weight; the original
+    /// waste is deactivated and the new waste IDs are returned
+    pub fn split_waste(
         env: Env,
-        materials: soroban_sdk::Vec<(WasteType, u64, String)>,
-        submitter: Address,
-    ) -> soroban_sdk::Vec<Material> {
-        // Validate submitter is registered
-        Self::only_registered(&env, &submitter);
+        waste_id: u128,
+        owner: Address,
+        split_weights: Vec<u128>,
+    ) -> Vec<u128> {
+        Self::only_waste_owner(&env, &owner, waste_id);
 
-        let mut results = soroban_sdk::Vec::new(&env);
-        let timestamp = env.ledger().timestamp();
+        let max_splits = Self::get_max_splits(env.clone());
+        validation::validate_split_weights(&split_weights, max_splits);
 
-        // Get or create stats once
-        let mut stats: RecyclingStats = env
+        let mut waste: types::Waste = env
             .storage()
             .instance()
-            .get(&("stats", submitter.clone()))
-            .unwrap_or_else(|| RecyclingStats::new(submitter.clone()));
-
-        let mut total_weight: u64 = 0;
+            .get(&("waste_v2", waste_id))
+            .expect("Waste item not found");
 
-        // Process each material
-        for item in materials.iter() {
-            let (waste_type, weight, description) = item;
-            let waste_id = Self::next_waste_id(&env);
+        if !waste.is_active {
+            panic!("Cannot split inactive waste");
+        }
 
-            let material = Material::new(
-                waste_id,
-                waste_type,
-                weight,
-                submitter.clone(),
-                timestamp,
-                description,
-            );
+        let mut total: u128 = 0;
+        for weight in split_weights.iter() {
+            total = total.checked_add(weight).expect("Overflow in split weights");
+        }
+        if total != waste.weight {
+            panic!("Split weights must sum to waste weight");
+        }
 
-            Self::set_waste(&env, waste_id, &material);
-            stats.record_submission(&material);
+        waste.deactivate();
+        env.storage()
+            .instance()
+            .set(&("waste_v2", waste_id), &waste);
+        Self::remove_active_wastes(&env, 1);
+        Self::remove_from_confirmed_index(&env, waste_id);
+
+        let mut waste_list: Vec<u128> = env
+            .storage()
+            .instance()
+            .get(&("participant_wastes", owner.clone()))
+            .unwrap_or(Vec::new(&env));
+        let mut new_ids = Vec::new(&env);
+        let timestamp = env.ledger().timestamp();
+
+        for weight in split_weights.iter() {
+            let new_id = Self::next_waste_id(&env) as u128;
+            let mut piece = types::Waste::new(
+                new_id,
+                waste.waste_type,
+                weight,
+                owner.clone(),
+                waste.latitude,
+                waste.longitude,
+                timestamp,
+                true,
+                false,
+                owner.clone(),
+            );
+            piece.submitter = waste.submitter.clone();
+            env.storage().instance().set(&("waste_v2", new_id), &piece);
+            Self::add_to_waste_type_index(&env, waste.waste_type, new_id);
+            waste_list.push_back(new_id);
+            new_ids.push_back(new_id);
+
+            events::emit_waste_registered(
+                &env,
+                new_id,
+                &owner,
+                waste.waste_type,
+                weight,
+                waste.latitude,
+                waste.longitude,
+            );
+        }
+
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", owner.clone()), &waste_list);
+        Self::add_active_wastes(&env, new_ids.len());
+
+        new_ids
+    }
+
+    /// Batch submit multiple materials for recycling
+    /// More efficient than individual submissions
+    /// Every material is attributed to the single `submitter`, whose stats are loaded and
+    /// saved once; use `submit_materials_batch_multi` for batches with several submitters
+    pub fn submit_materials_batch(
+        env: Env,
+        materials: soroban_sdk::Vec<(WasteType, u64, String)>,
+        submitter: Address,
+    ) -> soroban_sdk::Vec<Material> {
+        // Validate submitter is registered
+        Self::only_registered(&env, &submitter);
+
+        let mut results = soroban_sdk::Vec::new(&env);
+        let timestamp = env.ledger().timestamp();
+
+        // Get or create stats once
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", submitter.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(submitter.clone()));
+
+        let mut total_weight: u64 = 0;
+
+        // Process each material
+        for item in materials.iter() {
+            let (waste_type, weight, description) = item;
+            Self::require_waste_type_allowed(&env, waste_type);
+            let weight = Self::to_grams(&env, weight);
+            let waste_id = Self::next_waste_id(&env);
+
+            let material = Material::new(
+                waste_id,
+                waste_type,
+                weight,
+                submitter.clone(),
+                timestamp,
+                description,
+            );
+
+            Self::set_waste(&env, waste_id, &material);
+            Self::record_submission_history(&env, &submitter, waste_id);
+            stats.record_submission(&material);
             results.push_back(material);
 
             // Accumulate weight with overflow check
@@ -1504,6 +3103,74 @@ impl ScavengerContract {
         results
     }
 
+    /// Batch submit materials on behalf of several submitters
+    /// Each entry is (submitter, waste_type, weight, description); every distinct submitter
+    /// must be registered and authorize the call, and stats are accumulated per submitter
+    pub fn submit_materials_batch_multi(
+        env: Env,
+        entries: soroban_sdk::Vec<(Address, WasteType, u64, String)>,
+    ) -> soroban_sdk::Vec<Material> {
+        let mut results = soroban_sdk::Vec::new(&env);
+        let timestamp = env.ledger().timestamp();
+
+        let mut stats_by_submitter: Map<Address, RecyclingStats> = Map::new(&env);
+        let mut weight_by_submitter: Map<Address, u64> = Map::new(&env);
+
+        for entry in entries.iter() {
+            let (submitter, waste_type, weight, description) = entry;
+
+            let mut stats = match stats_by_submitter.get(submitter.clone()) {
+                Some(stats) => stats,
+                None => {
+                    Self::only_registered(&env, &submitter);
+                    env.storage()
+                        .instance()
+                        .get(&("stats", submitter.clone()))
+                        .unwrap_or_else(|| RecyclingStats::new(submitter.clone()))
+                }
+            };
+
+            Self::require_waste_type_allowed(&env, waste_type);
+            let weight = Self::to_grams(&env, weight);
+            let waste_id = Self::next_waste_id(&env);
+
+            let material = Material::new(
+                waste_id,
+                waste_type,
+                weight,
+                submitter.clone(),
+                timestamp,
+                description,
+            );
+
+            Self::set_waste(&env, waste_id, &material);
+            Self::record_submission_history(&env, &submitter, waste_id);
+            stats.record_submission(&material);
+            stats_by_submitter.set(submitter.clone(), stats);
+
+            let submitter_weight = weight_by_submitter
+                .get(submitter.clone())
+                .unwrap_or(0)
+                .checked_add(weight)
+                .expect("Overflow in batch weight");
+            weight_by_submitter.set(submitter, submitter_weight);
+
+            results.push_back(material);
+        }
+
+        // Save each submitter's stats once
+        for (submitter, stats) in stats_by_submitter.iter() {
+            env.storage()
+                .instance()
+                .set(&("stats", submitter.clone()), &stats);
+
+            let weight = weight_by_submitter.get(submitter.clone()).unwrap_or(0);
+            Self::update_participant_stats(&env, &submitter, weight, 0);
+        }
+
+        results
+    }
+
     /// Get material by ID (alias for backward compatibility)
     pub fn get_material(env: Env, material_id: u64) -> Option<Material> {
         Self::get_waste(env, material_id)
@@ -1521,6 +3188,26 @@ impl ScavengerContract {
         env.storage().instance().get(&key)
     }
 
+    /// Get when a material was verified
+    /// Returns 0 if the material is unverified, None if it does not exist
+    pub fn get_verification_timestamp(env: Env, material_id: u64) -> Option<u64> {
+        Self::get_waste_internal(&env, material_id).map(|material| material.verified_at)
+    }
+
+    /// Get the reward points a waste generated when it was verified
+    /// Returns 0 if the waste is unverified, None if it does not exist
+    pub fn get_waste_reward(env: Env, waste_id: u64) -> Option<u64> {
+        Self::get_waste_internal(&env, waste_id).map(|material| material.reward_points_earned)
+    }
+
+    /// Get the reward components of a waste as (base points, incentive bonus)
+    /// Base points are set at verification; the bonus accrues as incentive rewards are claimed
+    /// Returns None if the waste does not exist
+    pub fn get_reward_components(env: Env, waste_id: u64) -> Option<(u64, u64)> {
+        Self::get_waste_internal(&env, waste_id)
+            .map(|material| (material.reward_points_earned, material.incentive_bonus))
+    }
+
     /// Get all waste IDs owned by a participant
     /// Returns a vector of waste IDs where the participant is the current submitter/owner
     pub fn get_participant_wastes(env: Env, participant: Address) -> Vec<u64> {
@@ -1544,6 +3231,77 @@ impl ScavengerContract {
         waste_ids
     }
 
+    /// Get the IDs of a submitter's materials whose weight falls within `min..=max` grams
+    /// Returns an empty vector if `min > max`
+    pub fn get_materials_in_weight_range(env: Env, submitter: Address, min: u64, max: u64) -> Vec<u64> {
+        let mut result = Vec::new(&env);
+        if min > max {
+            return result;
+        }
+
+        for material_id in Self::get_participant_wastes(env.clone(), submitter).iter() {
+            if let Some(material) = Self::get_waste_internal(&env, material_id) {
+                if material.weight >= min && material.weight <= max {
+                    result.push_back(material_id);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Get the IDs of materials submitted by a participant, in submission order
+    pub fn get_submission_history(env: Env, participant: Address) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&("submission_history", participant))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get up to `limit` of the most recently submitted material IDs across all participants
+    /// Returned most recent first; only the last 20 submissions are retained
+    pub fn get_recent_submissions(env: Env, limit: u32) -> Vec<u64> {
+        let recent: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&RECENT_SUBMISSIONS)
+            .unwrap_or(Vec::new(&env));
+
+        let mut result = Vec::new(&env);
+        for material_id in recent.iter().rev().take(limit as usize) {
+            result.push_back(material_id);
+        }
+        result
+    }
+
+    /// Get the ID of a participant's most recently submitted material
+    /// Returns None if the participant has never submitted
+    pub fn get_latest_waste(env: Env, participant: Address) -> Option<u128> {
+        Self::get_submission_history(env, participant)
+            .last()
+            .map(|id| id as u128)
+    }
+
+    /// Append a material ID to a participant's submission history
+    fn record_submission_history(env: &Env, submitter: &Address, material_id: u64) {
+        let key = ("submission_history", submitter.clone());
+        let mut history: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        history.push_back(material_id);
+        env.storage().instance().set(&key, &history);
+
+        // Keep the global feed bounded by dropping the oldest entry
+        let mut recent: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&RECENT_SUBMISSIONS)
+            .unwrap_or(Vec::new(env));
+        recent.push_back(material_id);
+        if recent.len() > RECENT_SUBMISSIONS_CAP {
+            recent.pop_front();
+        }
+        env.storage().instance().set(&RECENT_SUBMISSIONS, &recent);
+    }
+
     /// Get multiple wastes by IDs (batch retrieval)
     pub fn get_wastes_batch(
         env: Env,
@@ -1574,19 +3332,61 @@ impl ScavengerContract {
             panic!("Verifier is not registered");
         }
 
-        if !participant.role.can_process_recyclables() {
+        if !Self::can_verify(&env, &participant.role) {
             panic!("Only recyclers can verify materials");
         }
 
-        // Get and verify material using new storage system
-        let mut material: Material =
-            Self::get_waste_internal(&env, material_id).expect("Material not found");
+        if Self::get_require_confirmation(env.clone()) {
+            let material =
+                Self::get_waste_internal(&env, material_id).expect("Material not found");
+            if !material.is_confirmed {
+                panic!("Waste not confirmed");
+            }
+        }
 
-        material.verify();
-        Self::set_waste(&env, material_id, &material);
+        Self::verify_by(&env, material_id, &verifier)
+    }
 
-        // Calculate tokens earned
-        let tokens_earned = material.calculate_reward_points();
+    /// Verify a material on behalf of a recycler, then apply auto-confirmation
+    /// and pay the verifier reward
+    fn verify_by(env: &Env, material_id: u64, verifier: &Address) -> Material {
+        let mut material = Self::apply_verification(env, material_id);
+
+        // Auto-confirm only if no third party has confirmed the material already
+        if !material.is_confirmed && env.storage().instance().get(&AUTO_CONFIRM).unwrap_or(false) {
+            material.confirm(verifier.clone());
+            Self::set_waste(env, material_id, &material);
+        }
+
+        // Compensate the verifier separately from the submitter's reward
+        let verifier_reward: u64 = env.storage().instance().get(&VERIFIER_REWARD).unwrap_or(0);
+        if verifier_reward > 0 {
+            Self::update_participant_stats(env, verifier, 0, verifier_reward);
+            events::emit_tokens_rewarded(env, verifier, verifier_reward as u128, material_id, 0);
+        }
+
+        material
+    }
+
+    /// Verify a material on behalf of the admin, bypassing recycler checks (admin only)
+    /// Intended for resolving disputes
+    pub fn admin_verify_material(env: Env, admin: Address, material_id: u64) -> Material {
+        Self::only_admin(&env, &admin);
+
+        let material = Self::apply_verification(&env, material_id);
+        events::emit_material_admin_verified(&env, material_id, &admin);
+
+        material
+    }
+
+    /// Mark a material as verified, update submitter stats and distribute rewards
+    fn apply_verification(env: &Env, material_id: u64) -> Material {
+        // Get and verify material using new storage system
+        let mut material: Material =
+            Self::get_waste_internal(env, material_id).expect("Material not found");
+        if material.verified {
+            panic!("Material already verified");
+        }
 
         // Update submitter stats
         let mut stats: RecyclingStats = env
@@ -1595,13 +3395,337 @@ impl ScavengerContract {
             .get(&("stats", material.submitter.clone()))
             .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
 
+        material.verify();
+        material.verified_at = env.ledger().timestamp();
+        Self::apply_reward_rules(env, &stats, &mut material);
+        Self::set_waste(env, material_id, &material);
+
+        // Calculate tokens earned
+        let tokens_earned = material.reward_points_earned;
+
         stats.record_verification(&material);
         env.storage()
             .instance()
             .set(&("stats", material.submitter.clone()), &stats);
+        Self::add_to_total_points(env, material.reward_points_earned);
 
         // Distribute token rewards using the helper which also emits TOKENS_REWARDED events
-        Self::_reward_tokens(&env, material_id, tokens_earned as u128);
+        Self::_reward_tokens(env, material_id, tokens_earned as u128);
+
+        // Pay the third party who confirmed the material before verification
+        if let Some(confirmer) = material.confirmer.clone() {
+            let confirmer_reward: u64 =
+                env.storage().instance().get(&CONFIRMER_REWARD).unwrap_or(0);
+            if confirmer_reward > 0 {
+                Self::update_participant_stats(env, &confirmer, 0, confirmer_reward);
+                events::emit_tokens_rewarded(env, &confirmer, confirmer_reward as u128, material_id, 0);
+            }
+        }
+
+        material
+    }
+
+    /// Adjust the points of a just-verified material according to the configured reward rules
+    /// Submissions below the minimum submissions threshold or the minimum reward weight earn
+    /// nothing; otherwise the per-submission base points are added to the weight-based points
+    fn apply_reward_rules(env: &Env, stats: &RecyclingStats, material: &mut Material) {
+        let verification_index = Self::next_verification_index(env);
+
+        let threshold: u32 = env.storage().instance().get(&MIN_SUBMISSIONS).unwrap_or(0);
+        let min_weight: u64 = env.storage().instance().get(&MIN_REWARD_WEIGHT).unwrap_or(0);
+        if stats.verified_submissions + 1 < threshold as u64 || material.weight < min_weight {
+            material.reward_points_earned = 0;
+            return;
+        }
+
+        // Metal and glass earn a percentage bonus on their computed points
+        let mut points = material.reward_points_earned;
+        if material.waste_type.is_infinitely_recyclable() {
+            let bonus_pct: u32 = env.storage().instance().get(&INFINITE_BONUS).unwrap_or(0);
+            let bonus = points
+                .checked_mul(bonus_pct as u64)
+                .expect("Overflow in reward points")
+                / 100;
+            points = points.checked_add(bonus).expect("Overflow in reward points");
+        }
+
+        let base_points: u64 = env.storage().instance().get(&BASE_POINTS).unwrap_or(0);
+        let points = points
+            .checked_add(base_points)
+            .and_then(|points| points.checked_add(Self::hop_bonus(env, material.id)))
+            .expect("Overflow in reward points");
+
+        // Halve the points once for every completed halving interval
+        let interval: u64 = env.storage().instance().get(&HALVING_INTERVAL).unwrap_or(0);
+        material.reward_points_earned = match verification_index.checked_div(interval) {
+            None => points,
+            Some(halvings) => u32::try_from(halvings)
+                .ok()
+                .and_then(|halvings| points.checked_shr(halvings))
+                .unwrap_or(0),
+        };
+    }
+
+    /// Bonus points for a material that has been transferred at least the configured number of times
+    fn hop_bonus(env: &Env, material_id: u64) -> u64 {
+        let min_hops: u32 = env.storage().instance().get(&MIN_HOPS).unwrap_or(0);
+        if min_hops == 0 {
+            return 0;
+        }
+
+        if Self::get_transfer_history(env.clone(), material_id).len() >= min_hops {
+            env.storage().instance().get(&HOP_BONUS).unwrap_or(0)
+        } else {
+            0
+        }
+    }
+
+    /// Set the number of transfers a material needs before verification earns the hop bonus (admin only)
+    /// A value of 0 disables the bonus
+    pub fn set_min_hops_for_bonus(env: Env, admin: Address, min_hops: u32) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&MIN_HOPS, &min_hops);
+    }
+
+    /// Get the number of transfers a material needs before verification earns the hop bonus
+    pub fn get_min_hops_for_bonus(env: Env) -> u32 {
+        env.storage().instance().get(&MIN_HOPS).unwrap_or(0)
+    }
+
+    /// Set the points added to the reward of a material verified after enough transfers (admin only)
+    pub fn set_hop_bonus_points(env: Env, admin: Address, points: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&HOP_BONUS, &points);
+    }
+
+    /// Get the points added to the reward of a material verified after enough transfers
+    pub fn get_hop_bonus_points(env: Env) -> u64 {
+        env.storage().instance().get(&HOP_BONUS).unwrap_or(0)
+    }
+
+    /// Set the percentage bonus on computed points for infinitely recyclable materials (admin only)
+    /// Applies to metal and glass; 0 disables the bonus
+    pub fn set_infinite_recyclable_bonus(env: Env, admin: Address, bonus_pct: u32) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&INFINITE_BONUS, &bonus_pct);
+    }
+
+    /// Get the percentage bonus on computed points for infinitely recyclable materials
+    pub fn get_infinite_recyclable_bonus(env: Env) -> u32 {
+        env.storage().instance().get(&INFINITE_BONUS).unwrap_or(0)
+    }
+
+    /// Return the number of verifications performed so far and count one more
+    fn next_verification_index(env: &Env) -> u64 {
+        let count: u64 = env.storage().instance().get(&VERIFICATION_COUNT).unwrap_or(0);
+        env.storage().instance().set(&VERIFICATION_COUNT, &(count + 1));
+        count
+    }
+
+    /// Set the number of global verifications after which rewards halve (admin only)
+    /// Each completed interval halves rewards again; 0 disables halving
+    pub fn set_reward_halving_interval(env: Env, admin: Address, interval: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&HALVING_INTERVAL, &interval);
+    }
+
+    /// Get the number of global verifications after which rewards halve (0 = never)
+    pub fn get_reward_halving_interval(env: Env) -> u64 {
+        env.storage().instance().get(&HALVING_INTERVAL).unwrap_or(0)
+    }
+
+    /// Get the total number of material verifications performed
+    pub fn get_verification_count(env: Env) -> u64 {
+        env.storage().instance().get(&VERIFICATION_COUNT).unwrap_or(0)
+    }
+
+    /// Confirm a submitted material as a third party
+    /// The confirmer is paid the configured confirmer reward once the material is verified
+    pub fn confirm_material(env: Env, material_id: u64, confirmer: Address) -> Material {
+        Self::only_registered(&env, &confirmer);
+
+        let mut material: Material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        if material.submitter == confirmer {
+            panic!("Submitter cannot confirm own material");
+        }
+
+        // Confirmation must come from a participant in a different role than the submitter
+        let confirmer_role = Self::get_participant(env.clone(), confirmer.clone()).map(|p| p.role);
+        let submitter_role =
+            Self::get_participant(env.clone(), material.submitter.clone()).map(|p| p.role);
+        if confirmer_role == submitter_role {
+            panic!("Confirmer must be a third party");
+        }
+
+        if material.is_confirmed {
+            panic!("Material already confirmed");
+        }
+
+        material.confirm(confirmer);
+        Self::set_waste(&env, material_id, &material);
+
+        material
+    }
+
+    /// Set the points credited to a confirmer when their confirmed material is verified (admin only)
+    /// A value of 0 disables confirmer rewards
+    pub fn set_confirmer_reward_points(env: Env, admin: Address, points: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&CONFIRMER_REWARD, &points);
+    }
+
+    /// Set the number of verified submissions a participant needs before earning rewards (admin only)
+    /// Verified submissions below the threshold earn no points; 0 disables the requirement
+    pub fn set_min_submissions_for_rewards(env: Env, admin: Address, count: u32) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&MIN_SUBMISSIONS, &count);
+    }
+
+    /// Get the number of verified submissions required before earning rewards
+    pub fn get_min_submissions_for_rewards(env: Env) -> u32 {
+        env.storage().instance().get(&MIN_SUBMISSIONS).unwrap_or(0)
+    }
+
+    /// Set the unit incoming submission weights are expressed in (admin only)
+    /// Weights are converted to grams on submission, so stats and rewards are unaffected
+    pub fn set_weight_unit(env: Env, admin: Address, unit: WeightUnit) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&WEIGHT_UNIT, &unit);
+    }
+
+    /// Get the unit incoming submission weights are expressed in (defaults to grams)
+    pub fn get_weight_unit(env: Env) -> WeightUnit {
+        env.storage().instance().get(&WEIGHT_UNIT).unwrap_or(WeightUnit::Grams)
+    }
+
+    /// Convert a submitted weight to grams using the configured weight unit
+    fn to_grams(env: &Env, weight: u64) -> u64 {
+        weight
+            .checked_mul(Self::get_weight_unit(env.clone()).grams_per_unit())
+            .expect("Overflow in weight conversion")
+    }
+
+    /// Set the minimum material weight, in grams, that earns points on verification (admin only)
+    /// Lighter materials are still verified but earn no points; 0 disables the requirement
+    pub fn set_min_reward_weight(env: Env, admin: Address, weight: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&MIN_REWARD_WEIGHT, &weight);
+    }
+
+    /// Get the minimum material weight, in grams, that earns points on verification
+    pub fn get_min_reward_weight(env: Env) -> u64 {
+        env.storage().instance().get(&MIN_REWARD_WEIGHT).unwrap_or(0)
+    }
+
+    /// Set the flat points added to every rewarded verified submission (admin only)
+    pub fn set_base_points_per_submission(env: Env, admin: Address, points: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&BASE_POINTS, &points);
+    }
+
+    /// Get the flat points added to every rewarded verified submission
+    pub fn get_base_points_per_submission(env: Env) -> u64 {
+        env.storage().instance().get(&BASE_POINTS).unwrap_or(0)
+    }
+
+    /// Set the points credited to a verifier for each verification (admin only)
+    /// A value of 0 disables verifier rewards
+    pub fn set_verifier_reward_points(env: Env, admin: Address, points: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&VERIFIER_REWARD, &points);
+    }
+
+    /// Get the points credited to a verifier for each verification
+    pub fn get_verifier_reward_points(env: Env) -> u64 {
+        env.storage().instance().get(&VERIFIER_REWARD).unwrap_or(0)
+    }
+
+    /// Enable or disable automatic confirmation by the verifier on verification (admin only)
+    pub fn set_auto_confirm_on_verify(env: Env, admin: Address, enabled: bool) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&AUTO_CONFIRM, &enabled);
+    }
+
+    /// Require materials to be confirmed by a third party before they can be verified (admin only)
+    pub fn set_require_confirmation(env: Env, admin: Address, required: bool) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&REQUIRE_CONFIRM, &required);
+    }
+
+    /// Check whether materials must be confirmed before they can be verified
+    pub fn get_require_confirmation(env: Env) -> bool {
+        env.storage().instance().get(&REQUIRE_CONFIRM).unwrap_or(false)
+    }
+
+    /// Allow Collectors, as well as Recyclers, to verify materials (admin only)
+    pub fn set_allow_collector_verification(env: Env, admin: Address, allowed: bool) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&COLLECTOR_VERIFY, &allowed);
+    }
+
+    /// Check whether Collectors may verify materials
+    pub fn get_allow_collector_verification(env: Env) -> bool {
+        env.storage().instance().get(&COLLECTOR_VERIFY).unwrap_or(false)
+    }
+
+    /// Check whether a role may verify materials under the current configuration
+    fn can_verify(env: &Env, role: &ParticipantRole) -> bool {
+        role.can_process_recyclables()
+            || (role.can_collect_materials() && Self::get_allow_collector_verification(env.clone()))
+    }
+
+    /// Set the window (seconds) during which a verification can be revoked (admin only)
+    /// A value of 0 disables the window
+    pub fn set_verification_revoke_window(env: Env, admin: Address, window: u64) {
+        Self::only_admin(&env, &admin);
+        env.storage().instance().set(&REVOKE_WINDOW, &window);
+    }
+
+    /// Revoke a material verification (admin only)
+    /// Rolls back the submitter's verification stats and the global points total;
+    /// distributed tokens are not clawed back
+    pub fn revoke_verification(env: Env, admin: Address, material_id: u64) -> Material {
+        Self::only_admin(&env, &admin);
+
+        let mut material: Material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
+
+        if !material.verified {
+            panic!("Material not verified");
+        }
+
+        let window: u64 = env.storage().instance().get(&REVOKE_WINDOW).unwrap_or(0);
+        let now = env.ledger().timestamp();
+        if window > 0 && now > material.verified_at.saturating_add(window) {
+            panic!("Revoke window elapsed");
+        }
+
+        let mut stats: RecyclingStats = env
+            .storage()
+            .instance()
+            .get(&("stats", material.submitter.clone()))
+            .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
+        stats.record_revocation(&material);
+        env.storage()
+            .instance()
+            .set(&("stats", material.submitter.clone()), &stats);
+
+        // Remove the material's points from the global points total
+        let points = material.reward_points_earned;
+        let total_points: u64 = env.storage().instance().get(&TOTAL_POINTS).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&TOTAL_POINTS, &total_points.saturating_sub(points));
+
+        material.revoke_verification();
+        Self::set_waste(&env, material_id, &material);
+
+        env.events().publish(
+            (symbol_short!("revoked"), material_id),
+            (admin, now),
+        );
 
         material
     }
@@ -1626,36 +3750,22 @@ impl ScavengerContract {
             panic!("Verifier is not registered");
         }
 
-        if !participant.role.can_process_recyclables() {
+        if !Self::can_verify(&env, &participant.role) {
             panic!("Only recyclers can verify materials");
         }
 
         let mut results = soroban_sdk::Vec::new(&env);
+        let require_confirmation = Self::get_require_confirmation(env.clone());
 
         for material_id in material_ids.iter() {
-            if let Some(mut material) = Self::get_waste_internal(&env, material_id) {
-                material.verify();
-                Self::set_waste(&env, material_id, &material);
-
-                // Calculate tokens earned
-                let tokens_earned = material.calculate_reward_points();
-
-                // Update submitter stats
-                let mut stats: RecyclingStats = env
-                    .storage()
-                    .instance()
-                    .get(&("stats", material.submitter.clone()))
-                    .unwrap_or_else(|| RecyclingStats::new(material.submitter.clone()));
-
-                stats.record_verification(&material);
-                env.storage()
-                    .instance()
-                    .set(&("stats", material.submitter.clone()), &stats);
-
-                // Distribute token rewards using the helper which also emits TOKENS_REWARDED events
-                Self::_reward_tokens(&env, material_id, tokens_earned as u128);
+            if let Some(material) = Self::get_waste_internal(&env, material_id) {
+                // Already verified materials are skipped, as are unconfirmed
+                // materials when confirmation is required
+                if material.verified || (require_confirmation && !material.is_confirmed) {
+                    continue;
+                }
 
-                results.push_back(material);
+                results.push_back(Self::verify_by(&env, material_id, &verifier));
             }
         }
 
@@ -1667,6 +3777,111 @@ impl ScavengerContract {
         env.storage().instance().get(&("stats", participant))
     }
 
+    /// Get a participant's total submitted weight (grams) per waste type
+    /// Returned as (paper, pet_plastic, plastic, metal, glass); all zero without submissions
+    pub fn get_type_weights(env: Env, participant: Address) -> (u64, u64, u64, u64, u64) {
+        Self::get_stats(env, participant)
+            .map(|stats| {
+                (
+                    stats.paper_weight,
+                    stats.pet_plastic_weight,
+                    stats.plastic_weight,
+                    stats.metal_weight,
+                    stats.glass_weight,
+                )
+            })
+            .unwrap_or((0, 0, 0, 0, 0))
+    }
+
+    /// Get the average submission weight (grams) for a participant
+    /// Returns 0 if the participant has no submissions
+    pub fn get_average_weight(env: Env, participant: Address) -> u64 {
+        Self::get_stats(env, participant)
+            .map(|stats| stats.average_weight())
+            .unwrap_or(0)
+    }
+
+    /// Get the total weight (grams) a participant has submitted
+    /// Returns 0 if the participant has no submissions
+    pub fn get_participant_total_weight(env: Env, participant: Address) -> u64 {
+        Self::get_stats(env, participant)
+            .map(|stats| stats.total_weight)
+            .unwrap_or(0)
+    }
+
+    /// Get a participant's reputation score
+    /// Computed as `verified_submissions * 10 + verification_rate + total_weight / 1000`;
+    /// returns 0 for unknown participants
+    pub fn get_reputation_score(env: Env, participant: Address) -> u64 {
+        Self::get_stats(env, participant)
+            .map(|stats| stats.reputation_score())
+            .unwrap_or(0)
+    }
+
+    /// Get verified vs unverified submission counts for a participant
+    /// Returns (verified, unverified), or (0, 0) for unknown participants
+    pub fn get_verification_counts(env: Env, participant: Address) -> (u64, u64) {
+        match Self::get_stats(env, participant) {
+            Some(stats) => (
+                stats.verified_submissions,
+                stats.total_submissions.saturating_sub(stats.verified_submissions),
+            ),
+            None => (0, 0),
+        }
+    }
+
+    /// Get the top participants by all-time reward points
+    /// Returns up to `limit` (address, points) pairs sorted by points descending
+    pub fn get_top_recyclers(env: Env, limit: u32) -> Vec<(Address, u64)> {
+        Self::rank_participants(&env, limit, |stats| stats.total_points)
+    }
+
+    /// Get the top participants by reward points earned in the current season
+    /// Returns up to `limit` (address, points) pairs sorted by points descending
+    pub fn get_season_leaderboard(env: Env, limit: u32) -> Vec<(Address, u64)> {
+        Self::rank_participants(&env, limit, |stats| stats.season_points)
+    }
+
+    /// Rank indexed participants by a points metric, skipping those with zero points
+    fn rank_participants(
+        env: &Env,
+        limit: u32,
+        points_of: impl Fn(&RecyclingStats) -> u64,
+    ) -> Vec<(Address, u64)> {
+        let mut results: Vec<(Address, u64)> = Vec::new(env);
+
+        for address in Self::get_participant_index(env).iter() {
+            let stats: Option<RecyclingStats> =
+                env.storage().instance().get(&("stats", address.clone()));
+            let points = match stats {
+                Some(stats) => points_of(&stats),
+                None => continue,
+            };
+            if points == 0 {
+                continue;
+            }
+
+            // Keep results sorted by points descending.
+            let mut inserted = false;
+            for idx in 0..results.len() {
+                if points > results.get(idx).unwrap().1 {
+                    results.insert(idx, (address.clone(), points));
+                    inserted = true;
+                    break;
+                }
+            }
+            if !inserted {
+                results.push_back((address, points));
+            }
+        }
+
+        while results.len() > limit {
+            results.pop_back();
+        }
+
+        results
+    }
+
     /// Get supply chain statistics (total wastes, total weight, total tokens earned)
     /// Returns a tuple of (total_wastes_count, total_weight_grams, total_tokens_earned)
     pub fn get_supply_chain_stats(env: Env) -> (u64, u64, u128) {
@@ -1677,13 +3892,47 @@ impl ScavengerContract {
         (total_wastes, total_weight, total_tokens)
     }
 
+    /// Get the ecosystem-wide sum of points awarded for verified materials
+    pub fn get_total_points_awarded(env: Env) -> u64 {
+        env.storage().instance().get(&TOTAL_POINTS).unwrap_or(0)
+    }
+
+    /// Get the number of wastes that are currently active (not deactivated)
+    pub fn get_total_active_wastes(env: Env) -> u32 {
+        env.storage().instance().get(&TOTAL_ACTIVE_WASTES).unwrap_or(0)
+    }
+
     /// Get all incentive IDs for a specific rewarder/manufacturer
     fn get_incentives_by_rewarder(env: Env, rewarder: Address) -> Vec<u64> {
         let key = ("rewarder_incentives", rewarder);
         env.storage().instance().get(&key).unwrap_or(Vec::new(&env))
     }
 
+    /// Get the number of incentives a manufacturer has created
+    pub fn get_manufacturer_incentive_count(env: Env, manufacturer: Address) -> u32 {
+        Self::get_incentives_by_rewarder(env, manufacturer).len()
+    }
+
+    /// Get the total remaining budget across a manufacturer's active incentives
+    pub fn get_manufacturer_total_budget(env: Env, manufacturer: Address) -> u64 {
+        let mut total: u64 = 0;
+
+        for incentive_id in Self::get_incentives_by_rewarder(env.clone(), manufacturer).iter() {
+            if let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) {
+                if incentive.active {
+                    total = total
+                        .checked_add(incentive.remaining_budget)
+                        .expect("Overflow in manufacturer budget");
+                }
+            }
+        }
+
+        total
+    }
+
     /// Get the active incentive with the highest reward for a specific manufacturer and waste type
+    /// Incentives are ranked by their per-match reward_points regardless of mode;
+    /// `estimate_incentive_payout` compares them by the payout for a specific waste instead
     /// Returns None if no active incentive is found
     pub fn get_active_mfr_incentive(
         env: Env,
@@ -1696,12 +3945,13 @@ impl ScavengerContract {
         
         let mut best_incentive: Option<Incentive> = None;
         let mut highest_reward: u64 = 0;
+        let now = env.ledger().timestamp();
 
         // Iterate through all incentives and find the best active one
         for incentive_id in incentive_ids.iter() {
             if let Some(incentive) = Self::get_incentive_internal(&env, incentive_id) {
-                // Check if incentive matches criteria: active and correct waste type
-                if incentive.active && incentive.waste_type == waste_type {
+                // Check if incentive matches criteria: available and correct waste type
+                if incentive.is_available(now) && incentive.waste_type == waste_type {
                     // Keep track of the incentive with highest reward
                     if incentive.reward_points > highest_reward {
                         highest_reward = incentive.reward_points;
@@ -1714,6 +3964,46 @@ impl ScavengerContract {
         best_incentive
     }
 
+    /// Estimate the incentive payout a waste would earn if delivered to a manufacturer
+    /// Uses the manufacturer's best active incentive for the waste type, capped by its
+    /// remaining budget; returns 0 if no incentive applies
+    pub fn estimate_incentive_payout(env: Env, waste_id: u128, manufacturer: Address) -> u64 {
+        let waste: types::Waste = env
+            .storage()
+            .instance()
+            .get(&("waste_v2", waste_id))
+            .expect("Waste item not found");
+
+        let weight = u64::try_from(waste.weight).unwrap_or(u64::MAX);
+        Self::best_mfr_incentive_payout(&env, &manufacturer, waste.waste_type, weight)
+    }
+
+    /// Get the highest payout any of a manufacturer's available incentives for a waste type
+    /// would pay for the given weight, so flat and per-kg incentives are compared by what
+    /// they actually pay; returns 0 if no incentive applies
+    fn best_mfr_incentive_payout(
+        env: &Env,
+        manufacturer: &Address,
+        waste_type: WasteType,
+        weight: u64,
+    ) -> u64 {
+        let key = ("rewarder_incentives", manufacturer.clone());
+        let incentive_ids: Vec<u64> = env.storage().instance().get(&key).unwrap_or(Vec::new(env));
+        let now = env.ledger().timestamp();
+
+        let mut best_payout: u64 = 0;
+        for incentive_id in incentive_ids.iter() {
+            if let Some(incentive) = Self::get_incentive_internal(env, incentive_id) {
+                if incentive.is_available(now) && incentive.waste_type == waste_type {
+                    let payout = Self::calculate_incentive_reward(env.clone(), incentive_id, weight);
+                    best_payout = best_payout.max(payout);
+                }
+            }
+        }
+
+        best_payout
+    }
+
     /// Create a new incentive
     pub fn create_incentive(
         env: Env,
@@ -1725,6 +4015,9 @@ impl ScavengerContract {
         // Access control check
         Self::only_manufacturer(&env, &rewarder);
 
+        // Validate reward against configured minimum
+        Self::require_min_incentive_reward(&env, reward_points);
+
         // Get next incentive ID
         let incentive_id = Self::next_incentive_id(&env);
 
@@ -1740,6 +4033,7 @@ impl ScavengerContract {
 
         // Store incentive
         Self::set_incentive(&env, incentive_id, &incentive);
+        Self::record_incentive_history(&env, &incentive);
 
         // Add to rewarder's incentive list
         let key = ("rewarder_incentives", rewarder.clone());
@@ -1749,11 +4043,14 @@ impl ScavengerContract {
         env.storage().instance().set(&key, &rewarder_incentives);
 
         // Add to general incentives list for this waste type
-        let key = ("general_incentives", waste_type);
-        let mut general_incentives: Vec<u64> =
-            env.storage().instance().get(&key).unwrap_or(Vec::new(&env));
+        let mut general_incentives = Self::get_general_incentives(&env, waste_type);
         general_incentives.push_back(incentive_id);
-        env.storage().instance().set(&key, &general_incentives);
+        env.storage()
+            .instance()
+            .set(&StorageKey::GeneralIncentives(waste_type), &general_incentives);
+        env.storage()
+            .instance()
+            .remove(&("general_incentives", waste_type));
 
         incentive
     }
@@ -1767,14 +4064,16 @@ impl ScavengerContract {
         claimer: Address,
     ) -> i128 {
         Self::only_registered(&env, &claimer);
+        Self::mark_incentive_claimed(&env, incentive_id, material_id);
 
         let mut incentive =
             Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
-        if !incentive.active {
+        if !incentive.is_available(env.ledger().timestamp()) {
             panic!("Incentive is not active");
         }
 
-        let material = Self::get_waste_internal(&env, material_id).expect("Material not found");
+        let mut material =
+            Self::get_waste_internal(&env, material_id).expect("Material not found");
         if !material.verified {
             panic!("Material not verified");
         }
@@ -1791,13 +4090,87 @@ impl ScavengerContract {
         if incentive.remaining_budget == 0 {
             incentive.active = false;
         }
+        incentive.record_match();
         Self::set_incentive(&env, incentive_id, &incentive);
 
+        material.incentive_bonus = material
+            .incentive_bonus
+            .checked_add(reward)
+            .expect("Overflow in incentive bonus");
+        Self::set_waste(&env, material_id, &material);
+
         Self::update_participant_stats(&env, &claimer, 0, reward);
+        events::emit_tokens_rewarded(&env, &claimer, reward as u128, material_id, incentive_id);
 
         reward as i128
     }
 
+    /// Explicitly claim an incentive's reward for one of the claimant's verified materials
+    /// Each (incentive, material) pair can only be claimed once
+    pub fn claim_against_incentive(
+        env: Env,
+        incentive_id: u64,
+        waste_id: u64,
+        claimant: Address,
+    ) -> u64 {
+        Self::only_registered(&env, &claimant);
+        Self::mark_incentive_claimed(&env, incentive_id, waste_id);
+
+        let mut incentive =
+            Self::get_incentive_internal(&env, incentive_id).expect("Incentive not found");
+        if !incentive.is_available(env.ledger().timestamp()) {
+            panic!("Incentive is not active");
+        }
+
+        let mut material = Self::get_waste_internal(&env, waste_id).expect("Material not found");
+        if material.submitter != claimant {
+            panic!("Claimant did not submit this waste");
+        }
+        if !material.verified {
+            panic!("Material not verified");
+        }
+        if material.waste_type != incentive.waste_type {
+            panic!("Waste type mismatch");
+        }
+
+        let reward = incentive
+            .claim_reward(material.weight)
+            .expect("Insufficient incentive budget");
+        if reward == 0 {
+            panic!("No reward available");
+        }
+        incentive.record_match();
+        Self::set_incentive(&env, incentive_id, &incentive);
+
+        material.incentive_bonus = material
+            .incentive_bonus
+            .checked_add(reward)
+            .expect("Overflow in incentive bonus");
+        Self::set_waste(&env, waste_id, &material);
+
+        Self::update_participant_stats(&env, &claimant, 0, reward);
+        events::emit_tokens_rewarded(&env, &claimant, reward as u128, waste_id, incentive_id);
+
+        reward
+    }
+
+    /// Record that an incentive has been claimed for a material
+    /// Shared by every claim path so a pair can never be paid twice
+    fn mark_incentive_claimed(env: &Env, incentive_id: u64, waste_id: u64) {
+        let claim_key = ("incentive_claim", incentive_id, waste_id);
+        if env.storage().instance().has(&claim_key) {
+            panic!("Incentive already claimed for this waste");
+        }
+        env.storage().instance().set(&claim_key, &true);
+    }
+
+    /// Check if an incentive has already been claimed for a material
+    pub fn is_incentive_claimed(env: Env, incentive_id: u64, waste_id: u64) -> bool {
+        env.storage()
+            .instance()
+            .has(&("incentive_claim", incentive_id, waste_id))
+    }
+
     /// Deactivate an incentive (only by creator)
     pub fn deactivate_incentive(env: Env, incentive_id: u64, rewarder: Address) -> Incentive {
         rewarder.require_auth();
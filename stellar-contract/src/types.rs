@@ -195,6 +195,71 @@ impl TransferRecord {
     }
 }
 
+/// How an incentive's payout is computed from the matched waste
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IncentiveMode {
+    /// Pays `reward_points` once regardless of weight
+    Flat = 0,
+    /// Pays `reward_points` for every whole kilogram
+    PerKg = 1,
+}
+
+/// Where rounding leftovers from reward distribution are sent
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingSink {
+    /// Added to the submitter's share
+    Recycler = 0,
+    /// Paid to the configured charity address
+    Charity = 1,
+    /// Held in the contract treasury balance
+    Treasury = 2,
+    /// Never credited; removed from the token supply
+    Burn = 3,
+}
+
+/// Unit in which integrators submit material weights
+/// Weights are always stored in grams
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WeightUnit {
+    Grams = 0,
+    Kilograms = 1,
+}
+
+impl WeightUnit {
+    /// Number of grams in one unit
+    pub fn grams_per_unit(&self) -> u64 {
+        match self {
+            WeightUnit::Grams => 1,
+            WeightUnit::Kilograms => 1000,
+        }
+    }
+}
+
+/// Auto-renewal setting for an incentive's budget
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AutoRenew {
+    /// The budget is never topped up
+    Off,
+    /// The budget is topped up on the given schedule
+    On(IncentiveRenewal),
+}
+
+/// Schedule for topping up an auto-renewing incentive's budget
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IncentiveRenewal {
+    /// Seconds between renewals
+    pub renew_period: u64,
+    /// Points added back to `remaining_budget` on each renewal
+    pub renew_amount: u64,
+    /// Start of the current renewal period
+    pub last_renewed_at: u64,
+}
+
 /// Represents an incentive offered by a manufacturer to encourage recycling
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -205,7 +270,7 @@ pub struct Incentive {
     pub rewarder: Address,
     /// Type of waste this incentive targets
     pub waste_type: WasteType,
-    /// Reward points per kilogram
+    /// Reward points (per kilogram in `PerKg` mode, per waste in `Flat` mode)
     pub reward_points: u64,
     /// Total points budget allocated for this incentive
     pub total_budget: u64,
@@ -215,6 +280,14 @@ pub struct Incentive {
     pub active: bool,
     /// Timestamp when the incentive was created
     pub created_at: u64,
+    /// Timestamp after which the incentive expires (0 = never expires)
+    pub expires_at: u64,
+    /// How the payout is computed
+    pub mode: IncentiveMode,
+    /// Periodic budget renewal settings
+    pub auto_renew: AutoRenew,
+    /// Number of wastes this incentive has paid out for
+    pub matches: u32,
 }
 
 impl Incentive {
@@ -236,18 +309,68 @@ impl Incentive {
             remaining_budget: total_budget,
             active: true,
             created_at,
+            expires_at: 0,
+            mode: IncentiveMode::PerKg,
+            auto_renew: AutoRenew::Off,
+            matches: 0,
         }
     }
 
+    /// Checks if the incentive has expired at the given timestamp
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at != 0 && now > self.expires_at
+    }
+
+    /// Checks if the incentive can currently pay out
+    /// (active, not expired and with budget remaining)
+    pub fn is_available(&self, now: u64) -> bool {
+        self.active && !self.is_expired(now) && self.remaining_budget > 0
+    }
+
     /// Deactivates the incentive
     pub fn deactivate(&mut self) {
         self.active = false;
     }
 
+    /// Counts one more waste rewarded by this incentive
+    pub fn record_match(&mut self) {
+        self.matches = self.matches.saturating_add(1);
+    }
+
+    /// Checks if an auto-renewal is due at the given timestamp
+    pub fn is_renewal_due(&self, now: u64) -> bool {
+        match &self.auto_renew {
+            AutoRenew::On(renewal) => {
+                now >= renewal.last_renewed_at.saturating_add(renewal.renew_period)
+            }
+            AutoRenew::Off => false,
+        }
+    }
+
+    /// Tops the remaining budget up by `renew_amount` (capped at `total_budget`)
+    /// and advances the renewal timestamp by one period; an incentive that was
+    /// deactivated by running out of budget becomes active again
+    pub fn renew(&mut self) {
+        if let AutoRenew::On(renewal) = &mut self.auto_renew {
+            let was_exhausted = self.remaining_budget == 0;
+            self.remaining_budget = self
+                .remaining_budget
+                .saturating_add(renewal.renew_amount)
+                .min(self.total_budget);
+            renewal.last_renewed_at = renewal.last_renewed_at.saturating_add(renewal.renew_period);
+            if was_exhausted && self.remaining_budget > 0 {
+                self.active = true;
+            }
+        }
+    }
+
     /// Calculates reward for a given weight in grams
     pub fn calculate_reward(&self, weight_grams: u64) -> u64 {
-        // Convert grams to kg and multiply by reward points
-        (weight_grams / 1000) * self.reward_points
+        match self.mode {
+            IncentiveMode::Flat => self.reward_points,
+            // Convert grams to kg and multiply by reward points
+            IncentiveMode::PerKg => (weight_grams / 1000) * self.reward_points,
+        }
     }
 
     /// Attempts to claim a reward, returns the amount claimed
@@ -433,6 +556,20 @@ pub struct Material {
     pub verified: bool,
     /// Optional description of the material
     pub description: String,
+    /// Latitude coordinate (scaled by 1e6, 0 if not provided)
+    pub latitude: i128,
+    /// Longitude coordinate (scaled by 1e6, 0 if not provided)
+    pub longitude: i128,
+    /// Timestamp when the material was verified (0 if unverified)
+    pub verified_at: u64,
+    /// Reward points generated when the material was verified (0 if unverified)
+    pub reward_points_earned: u64,
+    /// Incentive bonus paid out for this material (0 if none claimed)
+    pub incentive_bonus: u64,
+    /// Whether the material has been confirmed
+    pub is_confirmed: bool,
+    /// Address of the confirmer (None if unconfirmed)
+    pub confirmer: Option<Address>,
 }
 
 impl Material {
@@ -453,12 +590,39 @@ impl Material {
             submitted_at,
             verified: false,
             description,
+            latitude: 0,
+            longitude: 0,
+            verified_at: 0,
+            reward_points_earned: 0,
+            incentive_bonus: 0,
+            is_confirmed: false,
+            confirmer: None,
         }
     }
 
-    /// Marks the material as verified
+    /// Marks the material as verified and records the reward points it generated
     pub fn verify(&mut self) {
         self.verified = true;
+        self.reward_points_earned = self.calculate_reward_points();
+    }
+
+    /// Clears the verification status of the material
+    pub fn revoke_verification(&mut self) {
+        self.verified = false;
+        self.verified_at = 0;
+        self.reward_points_earned = 0;
+    }
+
+    /// Updates the location of the material
+    pub fn update_location(&mut self, latitude: i128, longitude: i128) {
+        self.latitude = latitude;
+        self.longitude = longitude;
+    }
+
+    /// Confirms the material with the given confirmer
+    pub fn confirm(&mut self, confirmer: Address) {
+        self.is_confirmed = true;
+        self.confirmer = Some(confirmer);
     }
 
     /// Checks if the material meets minimum weight requirement (100g)
@@ -507,10 +671,13 @@ pub struct Waste {
     pub is_confirmed: bool,
     /// Address of the confirmer/verifier
     pub confirmer: Address,
+    /// Address of the participant who originally submitted the waste (never changes)
+    pub submitter: Address,
 }
 
 impl Waste {
     /// Creates a new Waste instance with all fields
+    /// The initial owner is recorded as the original submitter
     pub fn new(
         waste_id: u128,
         waste_type: WasteType,
@@ -527,6 +694,7 @@ impl Waste {
             waste_id,
             waste_type,
             weight,
+            submitter: current_owner.clone(),
             current_owner,
             latitude,
             longitude,
@@ -703,6 +871,7 @@ impl WasteBuilder {
             waste_id: self.waste_id,
             waste_type: self.waste_type,
             weight: self.weight,
+            submitter: self.current_owner.clone(),
             current_owner: self.current_owner,
             latitude: self.latitude,
             longitude: self.longitude,
@@ -715,6 +884,17 @@ impl WasteBuilder {
 }
 
 
+/// Typed storage keys for per-waste-type indexes
+/// Each variant is a distinct key, so indexes for the same waste type never collide
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StorageKey {
+    /// IDs of wastes (v2) registered with a waste type
+    WasteTypeIndex(WasteType),
+    /// IDs of incentives created for a waste type
+    GeneralIncentives(WasteType),
+}
+
 /// Tracks recycling statistics for a participant
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -729,12 +909,22 @@ pub struct RecyclingStats {
     pub total_weight: u64,
     /// Total reward points earned
     pub total_points: u64,
+    /// Reward points earned in the current season
+    pub season_points: u64,
+    /// Reward points earned in the previous season
+    pub previous_season_points: u64,
     /// Number of materials by waste type
     pub paper_count: u64,
     pub pet_plastic_count: u64,
     pub plastic_count: u64,
     pub metal_count: u64,
     pub glass_count: u64,
+    /// Total weight in grams by waste type
+    pub paper_weight: u64,
+    pub pet_plastic_weight: u64,
+    pub plastic_weight: u64,
+    pub metal_weight: u64,
+    pub glass_weight: u64,
 }
 
 impl RecyclingStats {
@@ -746,11 +936,18 @@ impl RecyclingStats {
             verified_submissions: 0,
             total_weight: 0,
             total_points: 0,
+            season_points: 0,
+            previous_season_points: 0,
             paper_count: 0,
             pet_plastic_count: 0,
             plastic_count: 0,
             metal_count: 0,
             glass_count: 0,
+            paper_weight: 0,
+            pet_plastic_weight: 0,
+            plastic_weight: 0,
+            metal_weight: 0,
+            glass_weight: 0,
         }
     }
 
@@ -759,24 +956,55 @@ impl RecyclingStats {
         self.total_submissions += 1;
         self.total_weight += material.weight;
 
-        // Update waste type count
+        // Update waste type count and weight
         match material.waste_type {
-            WasteType::Paper => self.paper_count += 1,
-            WasteType::PetPlastic => self.pet_plastic_count += 1,
-            WasteType::Plastic => self.plastic_count += 1,
-            WasteType::Metal => self.metal_count += 1,
-            WasteType::Glass => self.glass_count += 1,
+            WasteType::Paper => {
+                self.paper_count += 1;
+                self.paper_weight += material.weight;
+            }
+            WasteType::PetPlastic => {
+                self.pet_plastic_count += 1;
+                self.pet_plastic_weight += material.weight;
+            }
+            WasteType::Plastic => {
+                self.plastic_count += 1;
+                self.plastic_weight += material.weight;
+            }
+            WasteType::Metal => {
+                self.metal_count += 1;
+                self.metal_weight += material.weight;
+            }
+            WasteType::Glass => {
+                self.glass_count += 1;
+                self.glass_weight += material.weight;
+            }
         }
     }
 
     /// Records a material verification
     pub fn record_verification(&mut self, material: &Material) {
         if material.verified {
+            let points = material.reward_points_earned;
             self.verified_submissions += 1;
-            self.total_points += material.calculate_reward_points();
+            self.total_points += points;
+            self.season_points += points;
         }
     }
 
+    /// Reverts a previously recorded material verification
+    pub fn record_revocation(&mut self, material: &Material) {
+        let points = material.reward_points_earned;
+        self.verified_submissions = self.verified_submissions.saturating_sub(1);
+        self.total_points = self.total_points.saturating_sub(points);
+        self.season_points = self.season_points.saturating_sub(points);
+    }
+
+    /// Closes the current season, carrying its points into `previous_season_points`
+    pub fn reset_season(&mut self) {
+        self.previous_season_points = self.season_points;
+        self.season_points = 0;
+    }
+
     /// Calculates the verification rate (percentage)
     pub fn verification_rate(&self) -> u64 {
         if self.total_submissions == 0 {
@@ -786,6 +1014,15 @@ impl RecyclingStats {
         }
     }
 
+    /// Computes a single reputation number for the participant:
+    /// `verified_submissions * 10 + verification_rate + total_weight / 1000`
+    pub fn reputation_score(&self) -> u64 {
+        self.verified_submissions
+            .saturating_mul(10)
+            .saturating_add(self.verification_rate())
+            .saturating_add(self.total_weight / 1000)
+    }
+
     /// Gets the most submitted waste type
     pub fn most_submitted_type(&self) -> Option<WasteType> {
         let counts = [
@@ -856,6 +1093,7 @@ mod recycling_stats_tests {
         assert_eq!(stats.total_submissions, 1);
         assert_eq!(stats.total_weight, 5000);
         assert_eq!(stats.paper_count, 1);
+        assert_eq!(stats.paper_weight, 5000);
     }
 
     #[test]
@@ -872,6 +1110,25 @@ mod recycling_stats_tests {
 
         assert_eq!(stats.verified_submissions, 1);
         assert_eq!(stats.total_points, 250); // 5kg * 5 * 10
+        assert_eq!(stats.season_points, 250);
+    }
+
+    #[test]
+    fn test_reset_season() {
+        let env = soroban_sdk::Env::default();
+        let participant = Address::generate(&env);
+        let description = String::from_str(&env, "Test");
+
+        let mut stats = RecyclingStats::new(participant.clone());
+        let mut material = Material::new(1, WasteType::Metal, 5000, participant, 0, description);
+
+        material.verify();
+        stats.record_verification(&material);
+        stats.reset_season();
+
+        assert_eq!(stats.season_points, 0);
+        assert_eq!(stats.previous_season_points, 250);
+        assert_eq!(stats.total_points, 250);
     }
 
     #[test]
@@ -886,6 +1143,20 @@ mod recycling_stats_tests {
         assert_eq!(stats.verification_rate(), 80);
     }
 
+    #[test]
+    fn test_reputation_score() {
+        let env = soroban_sdk::Env::default();
+        let participant = Address::generate(&env);
+
+        let mut stats = RecyclingStats::new(participant);
+        stats.total_submissions = 10;
+        stats.verified_submissions = 8;
+        stats.total_weight = 12_500;
+
+        // 8 * 10 + 80% + 12kg
+        assert_eq!(stats.reputation_score(), 172);
+    }
+
     #[test]
     fn test_most_submitted_type() {
         let env = soroban_sdk::Env::default();
@@ -993,8 +1264,10 @@ mod material_tests {
         );
 
         assert!(!material.verified);
+        assert_eq!(material.reward_points_earned, 0);
         material.verify();
         assert!(material.verified);
+        assert_eq!(material.reward_points_earned, 10);
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, Vec};
 
 pub fn validate_positive_amount(amount: i128, field_name: &str) {
     if amount <= 0 {
@@ -12,15 +12,16 @@ pub fn validate_percentage(percentage: u32, field_name: &str) {
     }
 }
 
-pub fn validate_coordinates(latitude: i128, longitude: i128) {
-    const MAX_LAT: i128 = 90_000_000;
-    const MAX_LON: i128 = 180_000_000;
+/// `scale` is the number of coordinate units per degree (e.g. 1_000_000 for microdegrees)
+pub fn validate_coordinates(latitude: i128, longitude: i128, scale: i128) {
+    let max_lat = 90i128.checked_mul(scale).expect("Coordinate scale too large");
+    let max_lon = 180i128.checked_mul(scale).expect("Coordinate scale too large");
 
-    if latitude < -MAX_LAT || latitude > MAX_LAT {
+    if latitude < -max_lat || latitude > max_lat {
         panic!("Latitude must be between -90 and +90 degrees");
     }
 
-    if longitude < -MAX_LON || longitude > MAX_LON {
+    if longitude < -max_lon || longitude > max_lon {
         panic!("Longitude must be between -180 and +180 degrees");
     }
 }
@@ -42,3 +43,13 @@ pub fn validate_positive_u128(amount: u128, field_name: &str) {
         panic!("{} must be greater than zero", field_name);
     }
 }
+
+pub fn validate_split_weights(split_weights: &Vec<u128>, max_splits: u32) {
+    if split_weights.is_empty() || split_weights.iter().any(|weight| weight == 0) {
+        panic!("Invalid split weights");
+    }
+
+    if split_weights.len() > max_splits {
+        panic!("Too many splits");
+    }
+}
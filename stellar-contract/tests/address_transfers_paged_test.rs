@@ -0,0 +1,52 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_pages_cover_all_transfers_in_order() {
+    let env = Env::default();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    // The collector receives two wastes and forwards one of them: three transfers
+    let w1 = client.recycle_waste(&WasteType::Paper, &1000, &recycler, &0, &0);
+    let w2 = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+    client.transfer_waste_v2(&w1, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&w2, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&w1, &collector, &manufacturer, &0, &0);
+
+    let first = client.get_address_transfers_paged(&collector, &0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(0).unwrap().waste_id, w1);
+    assert_eq!(first.get(0).unwrap().to, collector);
+    assert_eq!(first.get(1).unwrap().waste_id, w2);
+
+    let second = client.get_address_transfers_paged(&collector, &2, &2);
+    assert_eq!(second.len(), 1);
+    assert_eq!(second.get(0).unwrap().waste_id, w1);
+    assert_eq!(second.get(0).unwrap().from, collector);
+    assert_eq!(second.get(0).unwrap().to, manufacturer);
+
+    assert_eq!(client.get_address_transfers_paged(&collector, &3, &2).len(), 0);
+    assert_eq!(client.get_address_transfers_paged(&manufacturer, &0, &10).len(), 1);
+}
+
+#[test]
+fn test_unknown_address_returns_empty() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert_eq!(client.get_address_transfers_paged(&Address::generate(&env), &0, &10).len(), 0);
+}
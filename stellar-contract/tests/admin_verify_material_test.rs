@@ -0,0 +1,65 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Events, Address, Env, IntoVal, String, Symbol, Val, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+
+    (client, admin, submitter)
+}
+
+#[test]
+fn test_admin_can_verify_material() {
+    let env = Env::default();
+    let (client, admin, submitter) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    let verified = client.admin_verify_material(&admin, &material.id);
+
+    assert!(verified.verified);
+    assert!(client.get_material(&material.id).unwrap().verified);
+
+    let stats = client.get_stats(&submitter).unwrap();
+    assert_eq!(stats.verified_submissions, 1);
+    assert_eq!(stats.total_points, 100);
+}
+
+#[test]
+fn test_admin_verify_emits_distinct_event() {
+    let env = Env::default();
+    let (client, admin, submitter) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.admin_verify_material(&admin, &material.id);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (symbol_short!("material"), Symbol::new(&env, "admin_verified")).into_val(&env);
+    assert_eq!(topics, expected);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_non_admin_cannot_admin_verify() {
+    let env = Env::default();
+    let (client, _, submitter) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    client.admin_verify_material(&submitter, &material.id);
+}
+
+#[test]
+#[should_panic(expected = "Material already verified")]
+fn test_admin_verify_already_verified_panics() {
+    let env = Env::default();
+    let (client, admin, submitter) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    client.admin_verify_material(&admin, &material.id);
+    client.admin_verify_material(&admin, &material.id);
+}
@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+#[test]
+fn test_verify_confirms_when_enabled() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_auto_confirm_on_verify(&admin, &true);
+    assert!(client.get_config().auto_confirm_on_verify);
+
+    let material = client.submit_material(&WasteType::Glass, &2000, &submitter, &String::from_str(&env, "Jars"));
+    let verified = client.verify_material(&material.id, &verifier);
+
+    assert!(verified.is_confirmed);
+    assert_eq!(verified.confirmer, Some(verifier.clone()));
+
+    let stored = client.get_material(&material.id).unwrap();
+    assert!(stored.is_confirmed);
+    assert_eq!(stored.confirmer, Some(verifier));
+}
+
+#[test]
+fn test_verify_leaves_unconfirmed_by_default() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    assert!(!client.get_config().auto_confirm_on_verify);
+
+    let material = client.submit_material(&WasteType::Glass, &2000, &submitter, &String::from_str(&env, "Jars"));
+    let verified = client.verify_material(&material.id, &verifier);
+
+    assert!(verified.verified);
+    assert!(!verified.is_confirmed);
+    assert_eq!(verified.confirmer, None);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_auto_confirm_non_admin() {
+    let env = Env::default();
+    let (client, _, _, verifier) = setup(&env);
+
+    client.set_auto_confirm_on_verify(&verifier, &true);
+}
+
+#[test]
+fn test_batch_verify_confirms_when_enabled() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_auto_confirm_on_verify(&admin, &true);
+
+    let material = client.submit_material(&WasteType::Glass, &2000, &submitter, &String::from_str(&env, "Jars"));
+    let verified = client.verify_materials_batch(&soroban_sdk::vec![&env, material.id], &verifier);
+
+    assert!(verified.get(0).unwrap().is_confirmed);
+    let stored = client.get_material(&material.id).unwrap();
+    assert!(stored.is_confirmed);
+    assert_eq!(stored.confirmer, Some(verifier));
+}
+
+#[test]
+fn test_verify_keeps_existing_third_party_confirmer() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+    let confirmer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("conf"));
+
+    client.set_auto_confirm_on_verify(&admin, &true);
+
+    let material = client.submit_material(&WasteType::Glass, &2000, &submitter, &String::from_str(&env, "Jars"));
+    client.confirm_material(&material.id, &confirmer);
+    let verified = client.verify_material(&material.id, &verifier);
+
+    assert!(verified.is_confirmed);
+    assert_eq!(verified.confirmer, Some(confirmer.clone()));
+    assert_eq!(client.get_material(&material.id).unwrap().confirmer, Some(confirmer));
+}
@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+#[test]
+fn test_base_points_added_to_weight_points() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_base_points_per_submission(&admin, &5);
+    assert_eq!(client.get_base_points_per_submission(), 5);
+    assert_eq!(client.get_config().base_points_per_submission, 5);
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &String::from_str(&env, "Paper"));
+    let verified = client.verify_material(&material.id, &verifier);
+
+    assert_eq!(verified.reward_points_earned, 15);
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 15);
+    assert_eq!(client.get_total_points_awarded(), 15);
+}
+
+#[test]
+fn test_base_points_default_to_zero() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    assert_eq!(client.get_base_points_per_submission(), 0);
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &String::from_str(&env, "Paper"));
+    let verified = client.verify_material(&material.id, &verifier);
+    assert_eq!(verified.reward_points_earned, 10);
+}
+
+#[test]
+fn test_base_points_not_paid_below_submission_threshold() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_base_points_per_submission(&admin, &5);
+    client.set_min_submissions_for_rewards(&admin, &2);
+
+    let desc = String::from_str(&env, "Paper");
+    let first = client.submit_material(&WasteType::Paper, &1000, &submitter, &desc);
+    let second = client.submit_material(&WasteType::Paper, &1000, &submitter, &desc);
+
+    assert_eq!(client.verify_material(&first.id, &verifier).reward_points_earned, 0);
+    assert_eq!(client.verify_material(&second.id, &verifier).reward_points_earned, 15);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_base_points_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_base_points_per_submission(&submitter, &5);
+}
@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let mfr1 = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr1"));
+    let mfr2 = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr2"));
+
+    (client, mfr1, mfr2)
+}
+
+#[test]
+fn test_highest_reward_across_manufacturers() {
+    let env = Env::default();
+    let (client, mfr1, mfr2) = setup(&env);
+
+    client.create_incentive(&mfr1, &WasteType::Plastic, &20, &1000);
+    let top = client.create_incentive(&mfr2, &WasteType::Plastic, &50, &1000);
+    client.create_incentive(&mfr1, &WasteType::Plastic, &35, &1000);
+    // Higher reward for a different type is ignored
+    client.create_incentive(&mfr1, &WasteType::Metal, &90, &1000);
+
+    assert_eq!(client.get_best_incentive_for_type(&WasteType::Plastic).unwrap().id, top.id);
+}
+
+#[test]
+fn test_deactivated_top_incentive_skipped() {
+    let env = Env::default();
+    let (client, mfr1, mfr2) = setup(&env);
+
+    client.create_incentive(&mfr1, &WasteType::Plastic, &20, &1000);
+    let top = client.create_incentive(&mfr2, &WasteType::Plastic, &50, &1000);
+    let runner_up = client.create_incentive(&mfr1, &WasteType::Plastic, &35, &1000);
+
+    client.deactivate_incentive(&top.id, &mfr2);
+
+    assert_eq!(client.get_best_incentive_for_type(&WasteType::Plastic).unwrap().id, runner_up.id);
+}
+
+#[test]
+fn test_tie_broken_by_lowest_id() {
+    let env = Env::default();
+    let (client, mfr1, mfr2) = setup(&env);
+
+    let first = client.create_incentive(&mfr1, &WasteType::Glass, &40, &1000);
+    client.create_incentive(&mfr2, &WasteType::Glass, &40, &1000);
+
+    assert_eq!(client.get_best_incentive_for_type(&WasteType::Glass).unwrap().id, first.id);
+}
+
+#[test]
+fn test_no_incentives_returns_none() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert!(client.get_best_incentive_for_type(&WasteType::Paper).is_none());
+}
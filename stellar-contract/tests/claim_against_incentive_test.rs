@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, recycler, verifier, manufacturer)
+}
+
+fn verified_material(
+    env: &Env,
+    client: &ScavengerContractClient,
+    recycler: &Address,
+    verifier: &Address,
+    waste_type: WasteType,
+) -> u64 {
+    let material = client.submit_material(&waste_type, &3000, recycler, &String::from_str(env, "Bottles"));
+    client.verify_material(&material.id, verifier);
+    material.id
+}
+
+#[test]
+fn test_valid_claim_pays_and_marks_pair() {
+    let env = Env::default();
+    let (client, recycler, verifier, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &1000);
+    let waste_id = verified_material(&env, &client, &recycler, &verifier, WasteType::Plastic);
+    let earned_before = client.get_participant(&recycler).unwrap().total_tokens_earned;
+
+    let reward = client.claim_against_incentive(&incentive.id, &waste_id, &recycler);
+
+    // 3kg at 40 points per kg
+    assert_eq!(reward, 120);
+    assert!(client.is_incentive_claimed(&incentive.id, &waste_id));
+    assert_eq!(client.get_incentive_remaining_budget(&incentive.id), Some(880));
+    assert_eq!(client.get_material(&waste_id).unwrap().incentive_bonus, 120);
+    assert_eq!(client.get_participant(&recycler).unwrap().total_tokens_earned - earned_before, 120);
+}
+
+#[test]
+#[should_panic(expected = "Incentive already claimed for this waste")]
+fn test_duplicate_claim_panics() {
+    let env = Env::default();
+    let (client, recycler, verifier, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &1000);
+    let waste_id = verified_material(&env, &client, &recycler, &verifier, WasteType::Plastic);
+
+    client.claim_against_incentive(&incentive.id, &waste_id, &recycler);
+    client.claim_against_incentive(&incentive.id, &waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Waste type mismatch")]
+fn test_type_mismatch_panics() {
+    let env = Env::default();
+    let (client, recycler, verifier, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &1000);
+    let waste_id = verified_material(&env, &client, &recycler, &verifier, WasteType::Metal);
+
+    client.claim_against_incentive(&incentive.id, &waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Claimant did not submit this waste")]
+fn test_claim_by_non_submitter_panics() {
+    let env = Env::default();
+    let (client, recycler, verifier, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &1000);
+    let waste_id = verified_material(&env, &client, &recycler, &verifier, WasteType::Plastic);
+
+    client.claim_against_incentive(&incentive.id, &waste_id, &verifier);
+}
+
+#[test]
+#[should_panic(expected = "Material not verified")]
+fn test_unverified_material_panics() {
+    let env = Env::default();
+    let (client, recycler, _, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &1000);
+    let material = client.submit_material(&WasteType::Plastic, &3000, &recycler, &String::from_str(&env, "Bottles"));
+
+    client.claim_against_incentive(&incentive.id, &material.id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Incentive is not active")]
+fn test_incentive_without_budget_panics() {
+    let env = Env::default();
+    let (client, recycler, verifier, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &0);
+    let waste_id = verified_material(&env, &client, &recycler, &verifier, WasteType::Plastic);
+
+    client.claim_against_incentive(&incentive.id, &waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Incentive already claimed for this waste")]
+fn test_claim_through_legacy_path_then_explicit_panics() {
+    let env = Env::default();
+    let (client, recycler, verifier, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &1000);
+    let waste_id = verified_material(&env, &client, &recycler, &verifier, WasteType::Plastic);
+
+    client.claim_incentive_reward(&incentive.id, &waste_id, &recycler);
+    assert!(client.is_incentive_claimed(&incentive.id, &waste_id));
+    client.claim_against_incentive(&incentive.id, &waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Incentive already claimed for this waste")]
+fn test_claim_through_explicit_path_then_legacy_panics() {
+    let env = Env::default();
+    let (client, recycler, verifier, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &40, &1000);
+    let waste_id = verified_material(&env, &client, &recycler, &verifier, WasteType::Plastic);
+
+    client.claim_against_incentive(&incentive.id, &waste_id, &recycler);
+    client.claim_incentive_reward(&incentive.id, &waste_id, &recycler);
+}
@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("sub"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+
+    (client, admin, submitter, collector)
+}
+
+#[test]
+fn test_collector_verifies_when_allowed() {
+    let env = Env::default();
+    let (client, admin, submitter, collector) = setup(&env);
+
+    client.set_allow_collector_verification(&admin, &true);
+    assert!(client.get_allow_collector_verification());
+    assert!(client.get_config().allow_collector_verification);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    assert!(client.verify_material(&material.id, &collector).verified);
+
+    let batched = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    assert_eq!(client.verify_materials_batch(&vec![&env, batched.id], &collector).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Only recyclers can verify materials")]
+fn test_collector_rejected_by_default() {
+    let env = Env::default();
+    let (client, _, submitter, collector) = setup(&env);
+
+    assert!(!client.get_allow_collector_verification());
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.verify_material(&material.id, &collector);
+}
+
+#[test]
+#[should_panic(expected = "Only recyclers can verify materials")]
+fn test_manufacturer_still_rejected_when_allowed() {
+    let env = Env::default();
+    let (client, admin, submitter, _) = setup(&env);
+
+    let manufacturer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    client.set_allow_collector_verification(&admin, &true);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.verify_material(&material.id, &manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_allow_collector_verification_non_admin() {
+    let env = Env::default();
+    let (client, _, _, collector) = setup(&env);
+
+    client.set_allow_collector_verification(&collector, &true);
+}
@@ -0,0 +1,31 @@
+//! Shared setup for the integration tests
+#![allow(dead_code)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+/// Register a fresh contract with all auths mocked
+pub fn setup_contract(env: &Env) -> ScavengerContractClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    ScavengerContractClient::new(env, &contract_id)
+}
+
+/// Initialize the contract admin with a generated address
+pub fn init_admin(env: &Env, client: &ScavengerContractClient) -> Address {
+    let admin = Address::generate(env);
+    client.initialize_admin(&admin);
+    admin
+}
+
+/// Register a generated participant at the origin
+pub fn register(
+    env: &Env,
+    client: &ScavengerContractClient,
+    role: ParticipantRole,
+    name: Symbol,
+) -> Address {
+    let address = Address::generate(env);
+    client.register_participant(&address, &role, &name, &0, &0);
+    address
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("Rec"));
+
+    (client, recycler)
+}
+
+#[test]
+fn test_third_party_manufacturer_confirms() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    let manufacturer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("Mfr"));
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &2000, &recycler, &0, &0);
+    let waste = client.confirm_waste_details(&waste_id, &manufacturer);
+
+    assert!(waste.is_confirmed);
+    assert_eq!(waste.confirmer, manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Owner cannot confirm own waste")]
+fn test_owner_self_confirm_panics() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &2000, &recycler, &0, &0);
+    client.confirm_waste_details(&waste_id, &recycler);
+}
+
+#[test]
+#[should_panic(expected = "Confirmer must be a third party")]
+fn test_same_role_confirmer_panics() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    let other_recycler = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Rec2"));
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &2000, &recycler, &0, &0);
+    client.confirm_waste_details(&waste_id, &other_recycler);
+}
+
+#[test]
+#[should_panic(expected = "Confirmer must be a third party")]
+fn test_unregistered_confirmer_panics() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &2000, &recycler, &0, &0);
+    client.confirm_waste_details(&waste_id, &Address::generate(&env));
+}
@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let owner = common::register(env, &client, ParticipantRole::Collector, symbol_short!("owner"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("manu"));
+
+    (client, owner, manufacturer)
+}
+
+#[test]
+fn test_batch_counts_only_newly_confirmed() {
+    let env = Env::default();
+    let (client, owner, manufacturer) = setup(&env);
+
+    let w1 = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    let w2 = client.recycle_waste(&WasteType::Metal, &2000, &owner, &0, &0);
+    let w3 = client.recycle_waste(&WasteType::Glass, &3000, &owner, &0, &0);
+
+    client.confirm_waste_details(&w2, &manufacturer);
+
+    let count = client.confirm_wastes_batch(&vec![&env, w1, w2, w3], &manufacturer);
+    assert_eq!(count, 2);
+
+    assert!(client.get_waste_v2(&w1).unwrap().is_confirmed);
+    assert!(client.get_waste_v2(&w3).unwrap().is_confirmed);
+    assert_eq!(client.get_confirmed_wastes().len(), 3);
+}
+
+#[test]
+fn test_batch_skips_missing_and_invalid_wastes() {
+    let env = Env::default();
+    let (client, owner, manufacturer) = setup(&env);
+    let admin = common::init_admin(&env, &client);
+
+    let own = client.recycle_waste(&WasteType::Paper, &1000, &manufacturer, &0, &0);
+    let inactive = client.recycle_waste(&WasteType::Paper, &1000, &owner, &0, &0);
+    client.deactivate_waste(&inactive, &admin);
+    let valid = client.recycle_waste(&WasteType::Paper, &1000, &owner, &0, &0);
+
+    let count = client.confirm_wastes_batch(&vec![&env, own, inactive, 999, valid], &manufacturer);
+    assert_eq!(count, 1);
+    assert!(!client.get_waste_v2(&own).unwrap().is_confirmed);
+    assert!(client.get_waste_v2(&valid).unwrap().is_confirmed);
+}
+
+#[test]
+fn test_batch_skips_same_role_confirmer() {
+    let env = Env::default();
+    let (client, owner, _) = setup(&env);
+
+    let other_collector = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1000, &owner, &0, &0);
+
+    assert_eq!(client.confirm_wastes_batch(&vec![&env, waste_id], &other_collector), 0);
+}
@@ -0,0 +1,97 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let confirmer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("conf"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, confirmer, verifier)
+}
+
+#[test]
+fn test_confirmer_paid_when_material_verified() {
+    let env = Env::default();
+    let (client, admin, submitter, confirmer, verifier) = setup(&env);
+
+    client.set_confirmer_reward_points(&admin, &12);
+    assert_eq!(client.get_config().confirmer_reward_points, 12);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    let confirmed = client.confirm_material(&material.id, &confirmer);
+    assert!(confirmed.is_confirmed);
+    assert_eq!(confirmed.confirmer, Some(confirmer.clone()));
+
+    // Nothing is paid until verification
+    assert_eq!(client.get_participant(&confirmer).unwrap().total_tokens_earned, 0);
+
+    client.verify_material(&material.id, &verifier);
+    assert_eq!(client.get_participant(&confirmer).unwrap().total_tokens_earned, 12);
+}
+
+#[test]
+fn test_unconfirmed_material_pays_no_confirmer_reward() {
+    let env = Env::default();
+    let (client, admin, submitter, confirmer, verifier) = setup(&env);
+
+    client.set_confirmer_reward_points(&admin, &12);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(client.get_participant(&confirmer).unwrap().total_tokens_earned, 0);
+}
+
+#[test]
+#[should_panic(expected = "Submitter cannot confirm own material")]
+fn test_submitter_cannot_confirm_own_material() {
+    let env = Env::default();
+    let (client, _, submitter, _, _) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.confirm_material(&material.id, &submitter);
+}
+
+#[test]
+#[should_panic(expected = "Confirmer must be a third party")]
+fn test_same_role_participant_cannot_confirm_material() {
+    let env = Env::default();
+    let (client, _, submitter, _, _) = setup(&env);
+
+    let peer = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("peer"));
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.confirm_material(&material.id, &peer);
+}
+
+#[test]
+#[should_panic(expected = "Material already confirmed")]
+fn test_material_cannot_be_confirmed_twice() {
+    let env = Env::default();
+    let (client, _, submitter, confirmer, _) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.confirm_material(&material.id, &confirmer);
+    client.confirm_material(&material.id, &confirmer);
+}
+
+#[test]
+fn test_confirmer_paid_when_material_batch_verified() {
+    let env = Env::default();
+    let (client, admin, submitter, confirmer, verifier) = setup(&env);
+
+    client.set_confirmer_reward_points(&admin, &12);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.confirm_material(&material.id, &confirmer);
+
+    client.verify_materials_batch(&soroban_sdk::vec![&env, material.id], &verifier);
+    assert_eq!(client.get_participant(&confirmer).unwrap().total_tokens_earned, 12);
+}
@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+
+    (client, admin)
+}
+
+fn register_at(env: &Env, client: &ScavengerContractClient, latitude: i128, longitude: i128) {
+    client.register_participant(&Address::generate(env), &ParticipantRole::Recycler, &symbol_short!("x"), &latitude, &longitude);
+}
+
+#[test]
+fn test_default_scale_is_microdegrees() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(client.get_coordinate_scale(), 1_000_000);
+    assert_eq!(client.get_config().coordinate_scale, 1_000_000);
+
+    register_at(&env, &client, 90_000_000, 180_000_000);
+    register_at(&env, &client, -90_000_000, -180_000_000);
+}
+
+#[test]
+fn test_millidegree_scale_accepts_its_bounds() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.set_coordinate_scale(&admin, &1_000);
+    assert_eq!(client.get_coordinate_scale(), 1_000);
+
+    register_at(&env, &client, 90_000, 180_000);
+    register_at(&env, &client, -90_000, -180_000);
+}
+
+#[test]
+#[should_panic(expected = "Latitude must be between -90 and +90 degrees")]
+fn test_millidegree_scale_rejects_latitude_past_bound() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.set_coordinate_scale(&admin, &1_000);
+    register_at(&env, &client, 90_001, 0);
+}
+
+#[test]
+#[should_panic(expected = "Longitude must be between -180 and +180 degrees")]
+fn test_millidegree_scale_rejects_microdegree_longitude() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.set_coordinate_scale(&admin, &1_000);
+    // Valid under the default microdegree scale
+    register_at(&env, &client, 0, 1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Coordinate scale must be positive")]
+fn test_zero_scale_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+
+    client.set_coordinate_scale(&admin, &0);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_coordinate_scale_non_admin() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.set_coordinate_scale(&Address::generate(&env), &1_000);
+}
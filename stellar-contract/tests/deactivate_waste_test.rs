@@ -1,4 +1,3 @@
-use soroban_sdk::symbol_short;
 #![cfg(test)]
 
 use soroban_sdk::{testutils::Address as _, Address, Env};
@@ -180,7 +179,7 @@ fn test_deactivated_waste_not_counted_in_totals() {
 }
 
 #[test]
-#[should_panic(expected = "Cannot transfer deactivated waste")]
+#[should_panic(expected = "Cannot transfer inactive waste")]
 fn test_deactivated_waste_cannot_be_transferred() {
     let env = Env::default();
     env.mock_all_auths();
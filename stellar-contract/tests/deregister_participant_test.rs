@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, admin, recycler)
+}
+
+#[test]
+fn test_deregister_without_active_wastes() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    client.submit_material(&WasteType::Paper, &1000, &recycler, &String::from_str(&env, "Paper"));
+
+    let participant = client.deregister_participant(&recycler);
+    assert!(!participant.is_registered);
+
+    assert!(client.get_participant(&recycler).is_none());
+    assert!(!client.is_participant_registered(&recycler));
+
+    // Historical stats are preserved
+    let stats = client.get_stats(&recycler).unwrap();
+    assert_eq!(stats.total_submissions, 1);
+}
+
+#[test]
+fn test_deregister_after_waste_deactivated() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.deactivate_waste(&waste_id, &admin);
+
+    client.deregister_participant(&recycler);
+    assert!(client.get_participant(&recycler).is_none());
+
+    // Waste record is preserved
+    assert!(client.get_waste_v2(&waste_id).is_some());
+}
+
+#[test]
+fn test_reregister_after_deregister() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    client.deregister_participant(&recycler);
+    client.register_participant(&recycler, &ParticipantRole::Collector, &symbol_short!("rec"), &0, &0);
+
+    let participant = client.get_participant(&recycler).unwrap();
+    assert!(participant.is_registered);
+    assert_eq!(participant.role, ParticipantRole::Collector);
+}
+
+#[test]
+#[should_panic(expected = "Participant still owns active wastes")]
+fn test_deregister_with_active_wastes() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+
+    client.deregister_participant(&recycler);
+}
+
+#[test]
+#[should_panic(expected = "Participant not found")]
+fn test_deregister_unknown_participant() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    client.deregister_participant(&Address::generate(&env));
+}
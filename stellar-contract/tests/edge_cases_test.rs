@@ -369,7 +369,7 @@ fn test_non_owner_transfer_error() {
 }
 
 #[test]
-#[should_panic(expected = "Participant not found")]
+#[should_panic(expected = "Participant not registered")]
 fn test_update_nonexistent_participant_role() {
     let env = Env::default();
     let (client, _, _, _) = setup_contract(&env);
@@ -507,10 +507,11 @@ fn test_deregistered_participant() {
     let env = Env::default();
     let (client, _, recycler, _) = setup_contract(&env);
 
-    client.deregister_participant(&recycler);
-    
-    let participant = client.get_participant(&recycler).unwrap();
+    let participant = client.deregister_participant(&recycler);
     assert!(!participant.is_registered);
+
+    assert!(client.get_participant(&recycler).is_none());
+    assert!(!client.is_participant_registered(&recycler));
 }
 
 #[test]
@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{IncentiveMode, ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, collector, manufacturer)
+}
+
+#[test]
+fn test_estimate_uses_best_incentive() {
+    let env = Env::default();
+    let (client, collector, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Metal, &10, &10_000);
+    client.create_incentive(&manufacturer, &WasteType::Metal, &25, &10_000);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &4000, &collector, &0, &0);
+
+    // 4kg * 25 points
+    assert_eq!(client.estimate_incentive_payout(&waste_id, &manufacturer), 100);
+}
+
+#[test]
+fn test_estimate_capped_by_remaining_budget() {
+    let env = Env::default();
+    let (client, collector, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &120);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &5000, &collector, &0, &0);
+
+    // Uncapped would be 5kg * 50 = 250
+    assert_eq!(client.estimate_incentive_payout(&waste_id, &manufacturer), 120);
+}
+
+#[test]
+fn test_estimate_zero_without_matching_incentive() {
+    let env = Env::default();
+    let (client, collector, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Paper, &50, &1000);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &5000, &collector, &0, &0);
+
+    assert_eq!(client.estimate_incentive_payout(&waste_id, &manufacturer), 0);
+}
+
+#[test]
+#[should_panic(expected = "Waste item not found")]
+fn test_estimate_missing_waste() {
+    let env = Env::default();
+    let (client, _, manufacturer) = setup(&env);
+
+    client.estimate_incentive_payout(&999, &manufacturer);
+}
+
+#[test]
+fn test_estimate_ranks_flat_and_per_kg_by_payout() {
+    let env = Env::default();
+    let (client, collector, manufacturer) = setup(&env);
+
+    let flat = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &10_000);
+    client.set_incentive_mode(&flat.id, &IncentiveMode::Flat);
+    client.create_incentive(&manufacturer, &WasteType::Metal, &50, &10_000);
+
+    // 10kg: per-kg pays 500, flat pays 100
+    let heavy = client.recycle_waste(&WasteType::Metal, &10_000, &collector, &0, &0);
+    assert_eq!(client.estimate_incentive_payout(&heavy, &manufacturer), 500);
+
+    // 1kg: flat pays 100, per-kg pays 50
+    let light = client.recycle_waste(&WasteType::Metal, &1000, &collector, &0, &0);
+    assert_eq!(client.estimate_incentive_payout(&light, &manufacturer), 100);
+}
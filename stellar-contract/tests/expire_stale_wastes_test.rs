@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, admin, recycler)
+}
+
+#[test]
+fn test_stale_wastes_deactivated() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    client.set_waste_expiry(&admin, &1_000);
+    assert_eq!(client.get_waste_expiry(), 1_000);
+    assert_eq!(client.get_config().waste_expiry, 1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let old_first = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    let old_second = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 900);
+    let fresh = client.recycle_waste(&WasteType::Glass, &1000, &recycler, &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    assert_eq!(client.expire_stale_wastes(&admin, &10), 2);
+
+    assert!(!client.get_waste_v2(&old_first).unwrap().is_active);
+    assert!(!client.get_waste_v2(&old_second).unwrap().is_active);
+    assert!(client.get_waste_v2(&fresh).unwrap().is_active);
+
+    // Already deactivated wastes aren't counted again
+    assert_eq!(client.expire_stale_wastes(&admin, &10), 0);
+}
+
+#[test]
+fn test_limit_caps_deactivations() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    client.set_waste_expiry(&admin, &1_000);
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    let second = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    assert_eq!(client.expire_stale_wastes(&admin, &1), 1);
+
+    assert!(!client.get_waste_v2(&first).unwrap().is_active);
+    assert!(client.get_waste_v2(&second).unwrap().is_active);
+}
+
+#[test]
+fn test_zero_expiry_disables_pruning() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+
+    assert_eq!(client.expire_stale_wastes(&admin, &10), 0);
+    assert!(client.get_waste_v2(&waste_id).unwrap().is_active);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_expire_stale_wastes_non_admin() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    client.expire_stale_wastes(&recycler, &10);
+}
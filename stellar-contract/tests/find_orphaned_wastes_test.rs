@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = common::init_admin(env, &client);
+
+    (client, admin, contract_id)
+}
+
+fn register(env: &Env, client: &ScavengerContractClient) -> Address {
+    common::register(env, client, ParticipantRole::Recycler, symbol_short!("rec"))
+}
+
+/// Simulate a bad import by dropping a participant record directly from storage
+fn drop_participant_record(env: &Env, contract_id: &Address, participant: &Address) {
+    env.as_contract(contract_id, || {
+        env.storage().instance().remove(&(participant.clone(),));
+    });
+}
+
+#[test]
+fn test_waste_with_missing_owner_is_orphaned() {
+    let env = Env::default();
+    let (client, admin, contract_id) = setup(&env);
+    let kept = register(&env, &client);
+    let lost = register(&env, &client);
+
+    client.recycle_waste(&WasteType::Paper, &1000, &kept, &0, &0);
+    let orphan = client.recycle_waste(&WasteType::Metal, &1000, &lost, &0, &0);
+
+    assert_eq!(client.find_orphaned_wastes(&admin).len(), 0);
+
+    drop_participant_record(&env, &contract_id, &lost);
+
+    assert_eq!(client.find_orphaned_wastes(&admin), vec![&env, orphan]);
+}
+
+#[test]
+fn test_inactive_waste_is_not_orphaned() {
+    let env = Env::default();
+    let (client, admin, contract_id) = setup(&env);
+    let owner = register(&env, &client);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &1000, &owner, &0, &0);
+    client.deactivate_waste(&waste_id, &admin);
+    client.deregister_participant(&owner);
+    drop_participant_record(&env, &contract_id, &owner);
+
+    assert_eq!(client.find_orphaned_wastes(&admin).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_find_orphaned_wastes_non_admin() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    client.find_orphaned_wastes(&Address::generate(&env));
+}
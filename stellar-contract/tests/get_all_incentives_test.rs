@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, admin, manufacturer)
+}
+
+#[test]
+fn test_get_all_incentives_includes_inactive() {
+    let env = Env::default();
+    let (client, admin, manufacturer) = setup(&env);
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &2000);
+    let third = client.create_incentive(&manufacturer, &WasteType::Metal, &30, &3000);
+
+    client.update_incentive_status(&second.id, &false);
+
+    let all = client.get_all_incentives(&admin);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap().id, first.id);
+    assert_eq!(all.get(1).unwrap().id, second.id);
+    assert!(!all.get(1).unwrap().active);
+    assert_eq!(all.get(2).unwrap().id, third.id);
+
+    // Active-only query still excludes the deactivated one
+    assert_eq!(client.get_active_incentives().len(), 2);
+}
+
+#[test]
+fn test_get_all_incentives_empty() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    assert_eq!(client.get_all_incentives(&admin).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_get_all_incentives_non_admin() {
+    let env = Env::default();
+    let (client, _, manufacturer) = setup(&env);
+
+    client.get_all_incentives(&manufacturer);
+}
@@ -0,0 +1,29 @@
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+#[test]
+fn test_get_average_weight() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let recycler = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Rec"));
+
+    let desc = String::from_str(&env, "test");
+    client.submit_material(&WasteType::Paper, &1000, &recycler, &desc);
+    client.submit_material(&WasteType::Metal, &3000, &recycler, &desc);
+
+    assert_eq!(client.get_average_weight(&recycler), 2000);
+}
+
+#[test]
+fn test_get_average_weight_no_submissions() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let recycler = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Rec"));
+
+    assert_eq!(client.get_average_weight(&recycler), 0);
+    assert_eq!(client.get_average_weight(&Address::generate(&env)), 0);
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_get_config_reflects_configured_values() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let charity = Address::generate(&env);
+    let token = Address::generate(&env);
+
+    let admin = common::init_admin(&env, &client);
+    client.set_percentages(&admin, &10, &40);
+    client.set_charity_contract(&admin, &charity);
+    client.set_token_address(&admin, &token);
+
+    let config = client.get_config();
+    assert_eq!(config.admin, Some(admin));
+    assert_eq!(config.charity, Some(charity));
+    assert_eq!(config.token_address, Some(token));
+    assert_eq!(config.collector_percentage, 10);
+    assert_eq!(config.owner_percentage, 40);
+}
+
+#[test]
+fn test_get_config_defaults() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let config = client.get_config();
+    assert_eq!(config.admin, None);
+    assert_eq!(config.charity, None);
+    assert_eq!(config.token_address, None);
+    assert_eq!(config.collector_percentage, 5);
+    assert_eq!(config.owner_percentage, 50);
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let owner = common::register(env, &client, ParticipantRole::Collector, symbol_short!("owner"));
+    let confirmer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("conf"));
+
+    (client, owner, confirmer)
+}
+
+#[test]
+fn test_confirmed_index_tracks_confirm_and_reset() {
+    let env = Env::default();
+    let (client, owner, confirmer) = setup(&env);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    let second = client.recycle_waste(&WasteType::Metal, &2000, &owner, &0, &0);
+    let unconfirmed = client.recycle_waste(&WasteType::Glass, &3000, &owner, &0, &0);
+
+    client.confirm_waste_details(&first, &confirmer);
+    client.confirm_waste_details(&second, &confirmer);
+    assert_eq!(client.get_confirmed_wastes(), vec![&env, first, second]);
+
+    client.reset_waste_confirmation(&first, &owner);
+
+    let confirmed = client.get_confirmed_wastes();
+    assert_eq!(confirmed, vec![&env, second]);
+    assert!(!confirmed.contains(unconfirmed));
+}
+
+#[test]
+fn test_reconfirm_does_not_duplicate() {
+    let env = Env::default();
+    let (client, owner, confirmer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+
+    client.confirm_waste_details(&waste_id, &confirmer);
+    client.reset_waste_confirmation(&waste_id, &owner);
+    client.confirm_waste_details(&waste_id, &confirmer);
+
+    assert_eq!(client.get_confirmed_wastes(), vec![&env, waste_id]);
+}
+
+#[test]
+fn test_confirmed_index_empty() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert_eq!(client.get_confirmed_wastes().len(), 0);
+}
+
+#[test]
+fn test_deactivated_waste_leaves_confirmed_index() {
+    let env = Env::default();
+    let (client, owner, confirmer) = setup(&env);
+    let admin = common::init_admin(&env, &client);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    let second = client.recycle_waste(&WasteType::Metal, &2000, &owner, &0, &0);
+    client.confirm_waste_details(&first, &confirmer);
+    client.confirm_waste_details(&second, &confirmer);
+
+    client.deactivate_waste(&first, &admin);
+
+    assert_eq!(client.get_confirmed_wastes(), vec![&env, second]);
+}
+
+#[test]
+fn test_expired_waste_leaves_confirmed_index() {
+    let env = Env::default();
+    let (client, owner, confirmer) = setup(&env);
+    let admin = common::init_admin(&env, &client);
+    client.set_waste_expiry(&admin, &1_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let stale = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    env.ledger().with_mut(|li| li.timestamp = 900);
+    let fresh = client.recycle_waste(&WasteType::Metal, &2000, &owner, &0, &0);
+    client.confirm_waste_details(&stale, &confirmer);
+    client.confirm_waste_details(&fresh, &confirmer);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    assert_eq!(client.expire_stale_wastes(&admin, &10), 1);
+
+    assert_eq!(client.get_confirmed_wastes(), vec![&env, fresh]);
+}
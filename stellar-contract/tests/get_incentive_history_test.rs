@@ -0,0 +1,50 @@
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, vec, Env};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+#[test]
+fn test_incentive_history_records_create_and_updates_in_order() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let manufacturer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    env.ledger().with_mut(|li| li.timestamp = 100);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+
+    env.ledger().with_mut(|li| li.timestamp = 200);
+    client.update_incentive(&incentive.id, &20, &2000);
+
+    env.ledger().with_mut(|li| li.timestamp = 300);
+    client.update_incentive(&incentive.id, &30, &3000);
+
+    let history = client.get_incentive_history(&incentive.id);
+    assert_eq!(
+        history,
+        vec![&env, (100, 10, 1000), (200, 20, 2000), (300, 30, 3000)]
+    );
+}
+
+#[test]
+fn test_incentive_history_is_per_incentive() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let manufacturer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Metal, &50, &5000);
+    client.update_incentive(&second.id, &60, &6000);
+
+    assert_eq!(client.get_incentive_history(&first.id).len(), 1);
+    assert_eq!(client.get_incentive_history(&second.id).len(), 2);
+}
+
+#[test]
+fn test_incentive_history_missing_incentive() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    assert_eq!(client.get_incentive_history(&999).len(), 0);
+}
@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, manufacturer)
+}
+
+#[test]
+fn test_manufacturer_matches_creator() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let other = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("other"));
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    let second = client.create_incentive(&other, &WasteType::Metal, &20, &2000);
+
+    assert_eq!(client.get_incentive_manufacturer(&first.id), Some(manufacturer));
+    assert_eq!(client.get_incentive_manufacturer(&second.id), Some(other));
+}
+
+#[test]
+fn test_missing_incentive_returns_none() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(client.get_incentive_manufacturer(&999), None);
+}
+
+#[test]
+fn test_reward_points_match_created_incentive() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &35, &1000);
+
+    assert_eq!(client.get_incentive_reward_points(&incentive.id), Some(35));
+    assert_eq!(client.get_incentive_reward_points(&999), None);
+}
+
+#[test]
+fn test_is_incentive_active_reflects_status_and_budget() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let active = client.create_incentive(&manufacturer, &WasteType::Plastic, &35, &1000);
+    let deactivated = client.create_incentive(&manufacturer, &WasteType::Metal, &20, &1000);
+    let depleted = client.create_incentive(&manufacturer, &WasteType::Glass, &10, &0);
+
+    client.deactivate_incentive(&deactivated.id, &manufacturer);
+
+    assert_eq!(client.is_incentive_active(&active.id), Some(true));
+    assert_eq!(client.is_incentive_active(&deactivated.id), Some(false));
+    assert_eq!(client.is_incentive_active(&depleted.id), Some(false));
+    assert_eq!(client.is_incentive_active(&999), None);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use soroban_sdk::{symbol_short, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+#[test]
+fn test_remaining_budget_fresh_and_after_consumption() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let manufacturer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    let collector = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let recycler = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+    assert_eq!(client.get_incentive_remaining_budget(&incentive.id), Some(1000));
+
+    let material = client.submit_material(&WasteType::Metal, &3000, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+
+    // 3kg * 100 = 300 points consumed
+    assert_eq!(client.get_incentive_remaining_budget(&incentive.id), Some(700));
+}
+
+#[test]
+fn test_remaining_budget_missing_incentive() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    assert_eq!(client.get_incentive_remaining_budget(&999), None);
+}
+
+#[test]
+fn test_budget_spent_fresh_and_after_consumption() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let manufacturer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    let collector = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let recycler = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+    assert_eq!(client.get_incentive_budget_spent(&incentive.id), Some(0));
+
+    let material = client.submit_material(&WasteType::Metal, &3000, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+
+    assert_eq!(client.get_incentive_budget_spent(&incentive.id), Some(300));
+    assert_eq!(client.get_incentive_budget_spent(&999), None);
+}
+
+#[test]
+fn test_effective_reward_capped_by_remaining_budget() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let manufacturer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    let collector = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let recycler = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &350);
+    assert_eq!(client.get_effective_reward(&incentive.id), Some(100));
+
+    // 3kg * 100 = 300 points consumed, leaving 50
+    let material = client.submit_material(&WasteType::Metal, &3000, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+
+    assert_eq!(client.get_effective_reward(&incentive.id), Some(50));
+    assert_eq!(client.get_effective_reward(&999), None);
+}
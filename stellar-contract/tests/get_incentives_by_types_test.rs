@@ -0,0 +1,57 @@
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, manufacturer)
+}
+
+#[test]
+fn test_get_incentives_by_types_combines_plastic_and_metal() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let plastic = client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &1000);
+    let metal = client.create_incentive(&manufacturer, &WasteType::Metal, &50, &1000);
+    client.create_incentive(&manufacturer, &WasteType::Glass, &30, &1000);
+
+    let results = client.get_incentives_by_types(&vec![&env, WasteType::Plastic, WasteType::Metal]);
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().any(|i| i.id == plastic.id));
+    assert!(results.iter().any(|i| i.id == metal.id));
+    assert!(results.iter().all(|i| i.waste_type != WasteType::Glass));
+}
+
+#[test]
+fn test_get_incentives_by_types_deduplicates_repeated_types() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let plastic = client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &1000);
+    let metal = client.create_incentive(&manufacturer, &WasteType::Metal, &50, &1000);
+
+    let results = client.get_incentives_by_types(&vec![
+        &env,
+        WasteType::Plastic,
+        WasteType::Metal,
+        WasteType::Plastic,
+    ]);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().id, plastic.id);
+    assert_eq!(results.get(1).unwrap().id, metal.id);
+}
+
+#[test]
+fn test_get_incentives_by_types_empty_input() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &1000);
+
+    assert_eq!(client.get_incentives_by_types(&vec![&env]).len(), 0);
+}
@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, manufacturer)
+}
+
+#[test]
+fn test_only_sufficiently_funded_incentives_returned() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let small = client.create_incentive(&manufacturer, &WasteType::Plastic, &10, &100);
+    let exact = client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &500);
+    let large = client.create_incentive(&manufacturer, &WasteType::Plastic, &30, &2000);
+    // Different waste type is ignored even if funded
+    client.create_incentive(&manufacturer, &WasteType::Metal, &40, &5000);
+
+    let results = client.get_incentives_with_min_budget(&WasteType::Plastic, &500);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().id, large.id);
+    assert_eq!(results.get(1).unwrap().id, exact.id);
+    assert!(!results.iter().any(|i| i.id == small.id));
+}
+
+#[test]
+fn test_inactive_incentives_excluded() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Glass, &10, &1000);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    assert_eq!(client.get_incentives_with_min_budget(&WasteType::Glass, &1).len(), 0);
+}
+
+#[test]
+fn test_zero_min_budget_returns_all_active() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Paper, &10, &100);
+    client.create_incentive(&manufacturer, &WasteType::Paper, &20, &200);
+
+    assert_eq!(client.get_incentives_with_min_budget(&WasteType::Paper, &0).len(), 2);
+}
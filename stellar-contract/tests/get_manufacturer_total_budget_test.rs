@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, manufacturer)
+}
+
+#[test]
+fn test_total_budget_sums_active_incentives_only() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    client.create_incentive(&manufacturer, &WasteType::Metal, &20, &2500);
+    let inactive = client.create_incentive(&manufacturer, &WasteType::Glass, &30, &4000);
+    client.deactivate_incentive(&inactive.id, &manufacturer);
+
+    assert_eq!(client.get_manufacturer_total_budget(&manufacturer), 3500);
+}
+
+#[test]
+fn test_total_budget_ignores_other_manufacturers() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let other = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("other"));
+
+    client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    client.create_incentive(&other, &WasteType::Paper, &10, &9000);
+
+    assert_eq!(client.get_manufacturer_total_budget(&manufacturer), 1000);
+}
+
+#[test]
+fn test_total_budget_without_incentives() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    assert_eq!(client.get_manufacturer_total_budget(&manufacturer), 0);
+}
@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+#[test]
+fn test_batch_results_are_positional() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let unknown = Address::generate(&env);
+    let alice = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("alice"));
+    let bob = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("bob"));
+    client.submit_material(&WasteType::Paper, &1500, &alice, &String::from_str(&env, "Paper"));
+
+    let results = client.get_participant_info_batch(&vec![
+        &env,
+        alice.clone(),
+        unknown.clone(),
+        bob.clone(),
+        unknown,
+    ]);
+
+    assert_eq!(results.len(), 4);
+
+    let alice_info = results.get(0).unwrap().unwrap();
+    assert_eq!(alice_info.participant.address, alice);
+    assert_eq!(alice_info.stats.total_weight, 1500);
+
+    assert!(results.get(1).unwrap().is_none());
+
+    let bob_info = results.get(2).unwrap().unwrap();
+    assert_eq!(bob_info.participant.address, bob);
+    assert_eq!(bob_info.participant.role, ParticipantRole::Manufacturer);
+    assert_eq!(bob_info.stats.total_submissions, 0);
+
+    assert!(results.get(3).unwrap().is_none());
+}
+
+#[test]
+fn test_empty_batch_returns_empty() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    assert_eq!(client.get_participant_info_batch(&vec![&env]).len(), 0);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+#[test]
+fn test_get_participant_tokens_after_verified_metal_submission() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let recycler = Address::generate(&env);
+    let submitter = Address::generate(&env);
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("Rec"), &100, &200);
+    client.register_participant(&submitter, &ParticipantRole::Recycler, &symbol_short!("Sub"), &300, &400);
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &submitter, &String::from_str(&env, "cans"));
+    let verified = client.verify_material(&material.id, &recycler);
+
+    // Metal: 5kg * 5 multiplier * 10 = 250 points
+    assert_eq!(client.get_participant_tokens(&submitter), verified.calculate_reward_points());
+    assert_eq!(client.get_participant_tokens(&submitter), 250);
+}
+
+#[test]
+fn test_get_participant_tokens_new_participant() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let recycler = Address::generate(&env);
+
+    client.register_participant(&recycler, &ParticipantRole::Recycler, &symbol_short!("Rec"), &100, &200);
+
+    assert_eq!(client.get_participant_tokens(&recycler), 0);
+}
+
+#[test]
+fn test_get_participant_tokens_unregistered() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_participant_tokens(&stranger), 0);
+}
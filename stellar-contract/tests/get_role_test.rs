@@ -0,0 +1,35 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::ParticipantRole;
+
+#[test]
+fn test_get_role_for_collector() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let collector = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("col"));
+
+    assert_eq!(client.get_role(&collector), Some(ParticipantRole::Collector));
+}
+
+#[test]
+fn test_get_role_reflects_role_update() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let participant = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    client.update_role(&participant, &ParticipantRole::Manufacturer);
+
+    assert_eq!(client.get_role(&participant), Some(ParticipantRole::Manufacturer));
+}
+
+#[test]
+fn test_get_role_unregistered() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    assert_eq!(client.get_role(&Address::generate(&env)), None);
+}
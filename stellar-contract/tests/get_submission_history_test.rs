@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let alice = common::register(env, &client, ParticipantRole::Collector, symbol_short!("alice"));
+    let bob = common::register(env, &client, ParticipantRole::Collector, symbol_short!("bob"));
+
+    (client, alice, bob)
+}
+
+#[test]
+fn test_submission_history_in_order() {
+    let env = Env::default();
+    let (client, alice, bob) = setup(&env);
+    let desc = String::from_str(&env, "Item");
+
+    let first = client.submit_material(&WasteType::Paper, &1000, &alice, &desc);
+    // Interleave another participant's submission
+    client.submit_material(&WasteType::Metal, &1000, &bob, &desc);
+    let second = client.submit_material(&WasteType::Glass, &2000, &alice, &desc);
+    let third = client.submit_material(&WasteType::Plastic, &3000, &alice, &desc);
+
+    assert_eq!(
+        client.get_submission_history(&alice),
+        vec![&env, first.id, second.id, third.id]
+    );
+}
+
+#[test]
+fn test_submission_history_includes_batch_submissions() {
+    let env = Env::default();
+    let (client, alice, _) = setup(&env);
+    let desc = String::from_str(&env, "Item");
+
+    let single = client.submit_material(&WasteType::Paper, &1000, &alice, &desc);
+
+    let batch = vec![
+        &env,
+        (WasteType::Metal, 1000u64, desc.clone()),
+        (WasteType::Glass, 2000u64, desc.clone()),
+    ];
+    let submitted = client.submit_materials_batch(&batch, &alice);
+
+    assert_eq!(
+        client.get_submission_history(&alice),
+        vec![
+            &env,
+            single.id,
+            submitted.get(0).unwrap().id,
+            submitted.get(1).unwrap().id
+        ]
+    );
+}
+
+#[test]
+fn test_submission_history_empty() {
+    let env = Env::default();
+    let (client, alice, _) = setup(&env);
+
+    assert_eq!(client.get_submission_history(&alice).len(), 0);
+}
+
+#[test]
+fn test_get_latest_waste_returns_last_submission() {
+    let env = Env::default();
+    let (client, alice, bob) = setup(&env);
+    let desc = String::from_str(&env, "Item");
+
+    client.submit_material(&WasteType::Paper, &1000, &alice, &desc);
+    client.submit_material(&WasteType::Glass, &2000, &alice, &desc);
+    let third = client.submit_material(&WasteType::Metal, &3000, &alice, &desc);
+    client.submit_material(&WasteType::Metal, &3000, &bob, &desc);
+
+    assert_eq!(client.get_latest_waste(&alice), Some(third.id as u128));
+}
+
+#[test]
+fn test_get_latest_waste_without_submissions() {
+    let env = Env::default();
+    let (client, alice, _) = setup(&env);
+
+    assert_eq!(client.get_latest_waste(&alice), None);
+}
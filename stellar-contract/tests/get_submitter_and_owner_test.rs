@@ -0,0 +1,48 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_submitter_constant_while_owner_changes() {
+    let env = Env::default();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    assert_eq!(
+        client.get_submitter_and_owner(&waste_id),
+        Some((recycler.clone(), recycler.clone()))
+    );
+
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    assert_eq!(
+        client.get_submitter_and_owner(&waste_id),
+        Some((recycler.clone(), collector.clone()))
+    );
+
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+    assert_eq!(
+        client.get_submitter_and_owner(&waste_id),
+        Some((recycler, manufacturer))
+    );
+}
+
+#[test]
+fn test_get_submitter_and_owner_missing_waste() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert_eq!(client.get_submitter_and_owner(&999), None);
+}
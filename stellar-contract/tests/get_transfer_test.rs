@@ -0,0 +1,44 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_transfers_fetched_by_index() {
+    let env = Env::default();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+
+    let first = client.get_transfer(&(waste_id as u64), &0).unwrap();
+    assert_eq!(first.from, recycler);
+    assert_eq!(first.to, collector);
+
+    let second = client.get_transfer(&(waste_id as u64), &1).unwrap();
+    assert_eq!(second.from, collector);
+    assert_eq!(second.to, manufacturer);
+
+    assert!(client.get_transfer(&(waste_id as u64), &2).is_none());
+}
+
+#[test]
+fn test_missing_waste_returns_none() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert!(client.get_transfer(&999, &0).is_none());
+}
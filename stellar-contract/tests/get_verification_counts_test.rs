@@ -0,0 +1,53 @@
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+#[test]
+fn test_get_verification_counts_three_submitted_two_verified() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let submitter = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Sub"));
+    let verifier = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Ver"));
+
+    let desc = String::from_str(&env, "test");
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &submitter, &desc);
+    let m2 = client.submit_material(&WasteType::Metal, &2000, &submitter, &desc);
+    client.submit_material(&WasteType::Glass, &3000, &submitter, &desc);
+
+    assert_eq!(client.get_verification_counts(&submitter), (0, 3));
+
+    client.verify_material(&m1.id, &verifier);
+    client.verify_material(&m2.id, &verifier);
+
+    assert_eq!(client.get_verification_counts(&submitter), (2, 1));
+}
+
+#[test]
+fn test_get_verification_counts_unknown_participant() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_verification_counts(&stranger), (0, 0));
+}
+
+#[test]
+fn test_get_verification_counts_after_transfer_does_not_underflow() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let submitter = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Sub"));
+    let receiver = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("Rcv"));
+    let verifier = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Ver"));
+
+    // The receiver is verified for a material it never submitted
+    let desc = String::from_str(&env, "test");
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &desc);
+    client.transfer_waste(&material.id, &submitter, &receiver, &desc);
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(client.get_verification_counts(&receiver), (1, 0));
+}
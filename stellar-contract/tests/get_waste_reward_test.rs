@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, submitter, verifier)
+}
+
+#[test]
+fn test_get_waste_reward_after_verification() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Metal, &3000, &submitter, &String::from_str(&env, "Cans"));
+    let verified = client.verify_material(&material.id, &verifier);
+
+    // Metal: 3kg * 5 * 10 = 150 points
+    assert_eq!(verified.reward_points_earned, 150);
+    assert_eq!(client.get_waste_reward(&material.id), Some(150));
+}
+
+#[test]
+fn test_get_waste_reward_unverified_is_zero() {
+    let env = Env::default();
+    let (client, submitter, _) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Metal, &3000, &submitter, &String::from_str(&env, "Cans"));
+
+    assert_eq!(client.get_waste_reward(&material.id), Some(0));
+}
+
+#[test]
+fn test_get_waste_reward_batch_verification() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup(&env);
+
+    let paper = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    let glass = client.submit_material(&WasteType::Glass, &4000, &submitter, &String::from_str(&env, "Glass"));
+
+    let mut ids = soroban_sdk::Vec::new(&env);
+    ids.push_back(paper.id);
+    ids.push_back(glass.id);
+    client.verify_materials_batch(&ids, &verifier);
+
+    assert_eq!(client.get_waste_reward(&paper.id), Some(20));
+    assert_eq!(client.get_waste_reward(&glass.id), Some(80));
+}
+
+#[test]
+fn test_get_waste_reward_missing_waste() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert_eq!(client.get_waste_reward(&999), None);
+}
+
+#[test]
+fn test_reward_components_with_incentive_bonus() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup(&env);
+
+    let manufacturer = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &20, &10_000);
+
+    let material = client.submit_material(&WasteType::Metal, &3000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &verifier);
+    client.claim_incentive_reward(&incentive.id, &material.id, &submitter);
+
+    // Base: 3kg * 5 * 10 = 150, bonus: 3kg * 20 = 60
+    assert_eq!(client.get_reward_components(&material.id), Some((150, 60)));
+}
+
+#[test]
+fn test_reward_components_without_incentive() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Metal, &3000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(client.get_reward_components(&material.id), Some((150, 0)));
+    assert_eq!(client.get_reward_components(&999), None);
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("Rec"));
+
+    (client, recycler)
+}
+
+#[test]
+fn test_get_wastes_by_owner_and_type_filters_mixed_types() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    let plastic1 = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    let _metal = client.recycle_waste(&WasteType::Metal, &2000, &recycler, &0, &0);
+    let plastic2 = client.recycle_waste(&WasteType::Plastic, &3000, &recycler, &0, &0);
+    let _glass = client.recycle_waste(&WasteType::Glass, &4000, &recycler, &0, &0);
+
+    let plastics = client.get_wastes_by_owner_and_type(&recycler, &WasteType::Plastic);
+    assert_eq!(plastics, vec![&env, plastic1, plastic2]);
+
+    let papers = client.get_wastes_by_owner_and_type(&recycler, &WasteType::Paper);
+    assert_eq!(papers.len(), 0);
+}
+
+#[test]
+fn test_get_wastes_by_owner_and_type_excludes_other_owners() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    let other = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Other"));
+
+    let mine = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+    client.recycle_waste(&WasteType::Metal, &1000, &other, &0, &0);
+
+    let metals = client.get_wastes_by_owner_and_type(&recycler, &WasteType::Metal);
+    assert_eq!(metals, vec![&env, mine]);
+}
+
+#[test]
+fn test_get_wastes_by_owner_and_type_unknown_owner() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    let stranger = Address::generate(&env);
+    assert_eq!(client.get_wastes_by_owner_and_type(&stranger, &WasteType::Metal).len(), 0);
+}
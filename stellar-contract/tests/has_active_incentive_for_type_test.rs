@@ -0,0 +1,56 @@
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, manufacturer)
+}
+
+#[test]
+fn test_has_active_incentive_for_type_true() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &1000);
+
+    assert!(client.has_active_incentive_for_type(&WasteType::Plastic));
+}
+
+#[test]
+fn test_has_active_incentive_for_type_after_deactivation() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &1000);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    assert!(!client.has_active_incentive_for_type(&WasteType::Plastic));
+}
+
+#[test]
+fn test_has_active_incentive_for_type_none_created() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &1000);
+
+    assert!(!client.has_active_incentive_for_type(&WasteType::Glass));
+}
+
+#[test]
+fn test_has_active_incentive_for_type_ignores_expired() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &20, &1000);
+    client.set_incentive_expiry(&incentive.id, &100);
+
+    env.ledger().with_mut(|li| li.timestamp = 101);
+
+    assert!(!client.has_active_incentive_for_type(&WasteType::Metal));
+}
@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let other = common::register(env, &client, ParticipantRole::Collector, symbol_short!("oth"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    client.set_min_hops_for_bonus(&admin, &2);
+    client.set_hop_bonus_points(&admin, &30);
+
+    (client, admin, collector, other, verifier)
+}
+
+#[test]
+fn test_bonus_applied_with_enough_hops() {
+    let env = Env::default();
+    let (client, _, collector, other, verifier) = setup(&env);
+
+    assert_eq!(client.get_min_hops_for_bonus(), 2);
+    assert_eq!(client.get_hop_bonus_points(), 30);
+    assert_eq!(client.get_config().min_hops_for_bonus, 2);
+    assert_eq!(client.get_config().hop_bonus_points, 30);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &String::from_str(&env, "Cans"));
+    client.transfer_waste(&material.id, &collector, &other, &String::from_str(&env, "hop 1"));
+    client.transfer_waste(&material.id, &other, &collector, &String::from_str(&env, "hop 2"));
+
+    client.verify_material(&material.id, &verifier);
+
+    // 100 points for 2kg of metal plus the hop bonus
+    assert_eq!(client.get_waste_reward(&material.id), Some(130));
+}
+
+#[test]
+fn test_no_bonus_with_too_few_hops() {
+    let env = Env::default();
+    let (client, _, collector, other, verifier) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &String::from_str(&env, "Cans"));
+    client.transfer_waste(&material.id, &collector, &other, &String::from_str(&env, "hop 1"));
+
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(client.get_waste_reward(&material.id), Some(100));
+}
+
+#[test]
+fn test_zero_min_hops_disables_bonus() {
+    let env = Env::default();
+    let (client, admin, collector, _, verifier) = setup(&env);
+
+    client.set_min_hops_for_bonus(&admin, &0);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(client.get_waste_reward(&material.id), Some(100));
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_hop_bonus_non_admin() {
+    let env = Env::default();
+    let (client, _, collector, _, _) = setup(&env);
+
+    client.set_hop_bonus_points(&collector, &30);
+}
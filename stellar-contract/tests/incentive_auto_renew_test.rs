@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env, String};
+use stellar_scavngr_contract::{AutoRenew, ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, manufacturer, collector, recycler)
+}
+
+/// Submit, verify and claim 5kg of metal against the incentive
+fn spend(env: &Env, client: &ScavengerContractClient, incentive_id: u64, collector: &Address, recycler: &Address) {
+    let material = client.submit_material(&WasteType::Metal, &5000, collector, &String::from_str(env, "Cans"));
+    client.verify_material(&material.id, recycler);
+    client.claim_incentive_reward(&incentive_id, &material.id, collector);
+}
+
+#[test]
+fn test_renewal_restores_budget_once_per_period() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+    let configured = client.set_incentive_auto_renew(&incentive.id, &3_600, &500);
+    let renewal = match configured.auto_renew {
+        AutoRenew::On(renewal) => renewal,
+        AutoRenew::Off => panic!("auto-renew not enabled"),
+    };
+    assert_eq!(renewal.renew_period, 3_600);
+    assert_eq!(renewal.renew_amount, 500);
+    assert_eq!(renewal.last_renewed_at, 1_000);
+
+    spend(&env, &client, incentive.id, &collector, &recycler);
+    assert_eq!(client.get_incentive_remaining_budget(&incentive.id), Some(500));
+
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    let renewed = client.renew_incentive(&incentive.id);
+    assert_eq!(renewed.remaining_budget, 1000);
+    assert!(matches!(renewed.auto_renew, AutoRenew::On(ref r) if r.last_renewed_at == 4_600));
+
+    // Top-up never exceeds the total budget
+    env.ledger().with_mut(|li| li.timestamp = 8_200);
+    assert_eq!(client.renew_incentive(&incentive.id).remaining_budget, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Renewal period not elapsed")]
+fn test_second_renewal_in_same_period_panics() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+    client.set_incentive_auto_renew(&incentive.id, &3_600, &500);
+    spend(&env, &client, incentive.id, &collector, &recycler);
+
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    client.renew_incentive(&incentive.id);
+    client.renew_incentive(&incentive.id);
+}
+
+#[test]
+#[should_panic(expected = "Incentive does not auto-renew")]
+fn test_renew_without_auto_renew_panics() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+    assert_eq!(incentive.auto_renew, AutoRenew::Off);
+
+    client.renew_incentive(&incentive.id);
+}
+
+#[test]
+fn test_renewal_reactivates_exhausted_incentive() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &500);
+    client.set_incentive_auto_renew(&incentive.id, &3_600, &500);
+
+    // 5kg * 100 drains the whole budget and deactivates the incentive
+    spend(&env, &client, incentive.id, &collector, &recycler);
+    assert_eq!(client.get_incentive_remaining_budget(&incentive.id), Some(0));
+    assert_eq!(client.is_incentive_active(&incentive.id), Some(false));
+
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    let renewed = client.renew_incentive(&incentive.id);
+    assert_eq!(renewed.remaining_budget, 500);
+    assert!(renewed.active);
+    assert_eq!(client.is_incentive_active(&incentive.id), Some(true));
+
+    // The renewed budget can be claimed again
+    spend(&env, &client, incentive.id, &collector, &recycler);
+    assert_eq!(client.get_incentive_remaining_budget(&incentive.id), Some(0));
+}
+
+#[test]
+fn test_renewal_keeps_manually_deactivated_incentive_inactive() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+    client.set_incentive_auto_renew(&incentive.id, &3_600, &500);
+    client.deactivate_incentive(&incentive.id, &manufacturer);
+
+    env.ledger().with_mut(|li| li.timestamp = 3_600);
+    assert!(!client.renew_incentive(&incentive.id).active);
+}
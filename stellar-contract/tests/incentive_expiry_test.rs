@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::{Address as _, Ledger}, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup_manufacturer(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = Address::generate(env);
+
+    client.register_participant(
+        &manufacturer,
+        &ParticipantRole::Manufacturer,
+        &symbol_short!("mfr"),
+        &0,
+        &0,
+    );
+
+    (client, manufacturer)
+}
+
+#[test]
+fn test_never_expiring_incentive_is_not_expired() {
+    let env = Env::default();
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &5000);
+    assert_eq!(incentive.expires_at, 0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+
+    assert_eq!(client.is_incentive_expired(&incentive.id), Some(false));
+}
+
+#[test]
+fn test_incentive_expired_after_expiry_timestamp() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &5000);
+    client.set_incentive_expiry(&incentive.id, &2_000);
+
+    // Still valid at the expiry timestamp itself
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.is_incentive_expired(&incentive.id), Some(false));
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    assert_eq!(client.is_incentive_expired(&incentive.id), Some(true));
+}
+
+#[test]
+fn test_is_incentive_expired_missing_incentive() {
+    let env = Env::default();
+    let (client, _) = setup_manufacturer(&env);
+
+    assert_eq!(client.is_incentive_expired(&999), None);
+}
+
+#[test]
+#[should_panic(expected = "Incentive not found")]
+fn test_set_incentive_expiry_missing_incentive() {
+    let env = Env::default();
+    let (client, _) = setup_manufacturer(&env);
+
+    client.set_incentive_expiry(&999, &2_000);
+}
+
+#[test]
+#[should_panic(expected = "Incentive is not active")]
+fn test_claim_after_expiry_panics() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let collector = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let recycler = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &5000);
+    client.set_incentive_expiry(&incentive.id, &2_000);
+
+    let material = client.submit_material(
+        &WasteType::Plastic,
+        &1000,
+        &collector,
+        &soroban_sdk::String::from_str(&env, "Bottles"),
+    );
+    client.verify_material(&material.id, &recycler);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+}
+
+#[test]
+fn test_expired_incentive_hidden_from_queries() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, manufacturer) = setup_manufacturer(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &50, &5000);
+    client.set_incentive_expiry(&incentive.id, &2_000);
+    assert_eq!(client.calculate_incentive_reward(&incentive.id, &1000), 50);
+
+    env.ledger().with_mut(|li| li.timestamp = 2_001);
+    assert_eq!(client.calculate_incentive_reward(&incentive.id, &1000), 0);
+    assert_eq!(client.get_incentives_by_waste_type(&WasteType::Plastic).len(), 0);
+    assert_eq!(client.get_active_incentives().len(), 0);
+    assert!(client.get_active_mfr_incentive(&manufacturer, &WasteType::Plastic).is_none());
+}
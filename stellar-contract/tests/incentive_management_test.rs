@@ -53,7 +53,7 @@ fn test_non_manufacturer_creation_fails_collector() {
 }
 
 #[test]
-#[should_panic(expected = "Caller is not a registered participant")]
+#[should_panic(expected = "Participant not registered")]
 fn test_unregistered_creation_fails() {
     let env = Env::default();
     let contract_id = env.register_contract(None, ScavengerContract);
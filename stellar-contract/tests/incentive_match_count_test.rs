@@ -0,0 +1,47 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, collector, recycler, manufacturer)
+}
+
+fn verified_material(env: &Env, client: &ScavengerContractClient, collector: &Address, recycler: &Address) -> u64 {
+    let material = client.submit_material(&WasteType::Metal, &2000, collector, &String::from_str(env, "Cans"));
+    client.verify_material(&material.id, recycler);
+    material.id
+}
+
+#[test]
+fn test_two_payouts_counted() {
+    let env = Env::default();
+    let (client, collector, recycler, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &10, &1000);
+    assert_eq!(client.get_incentive_match_count(&incentive.id), Some(0));
+
+    let first = verified_material(&env, &client, &collector, &recycler);
+    let second = verified_material(&env, &client, &collector, &recycler);
+
+    client.claim_incentive_reward(&incentive.id, &first, &collector);
+    client.claim_against_incentive(&incentive.id, &second, &collector);
+
+    assert_eq!(client.get_incentive_match_count(&incentive.id), Some(2));
+}
+
+#[test]
+fn test_missing_incentive_returns_none() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert_eq!(client.get_incentive_match_count(&999), None);
+}
@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{IncentiveMode, ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, manufacturer, collector, recycler)
+}
+
+#[test]
+fn test_per_kg_mode_scales_with_weight() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &40, &10_000);
+    assert_eq!(incentive.mode, IncentiveMode::PerKg);
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material.id, &collector), 200);
+}
+
+#[test]
+fn test_flat_mode_pays_reward_points() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &40, &10_000);
+    let updated = client.set_incentive_mode(&incentive.id, &IncentiveMode::Flat);
+    assert_eq!(updated.mode, IncentiveMode::Flat);
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+
+    assert_eq!(client.calculate_incentive_reward(&incentive.id, &5000), 40);
+    assert_eq!(client.claim_incentive_reward(&incentive.id, &material.id, &collector), 40);
+}
+
+#[test]
+#[should_panic(expected = "Incentive not found")]
+fn test_set_incentive_mode_missing_incentive() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    client.set_incentive_mode(&999, &IncentiveMode::Flat);
+}
+
+#[test]
+fn test_normalized_reward_comparable_across_modes() {
+    let env = Env::default();
+    let (client, manufacturer, _, _) = setup(&env);
+
+    let flat = client.create_incentive(&manufacturer, &WasteType::Metal, &40, &10_000);
+    client.set_incentive_mode(&flat.id, &IncentiveMode::Flat);
+    let per_kg = client.create_incentive(&manufacturer, &WasteType::Metal, &25, &10_000);
+
+    let flat_rate = client.get_normalized_reward_per_kg(&flat.id).unwrap();
+    let per_kg_rate = client.get_normalized_reward_per_kg(&per_kg.id).unwrap();
+
+    assert_eq!(flat_rate, 40);
+    assert_eq!(per_kg_rate, 25);
+    assert!(flat_rate > per_kg_rate);
+
+    assert_eq!(client.get_normalized_reward_per_kg(&999), None);
+}
@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = common::init_admin(env, &client);
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, contract_id, admin, collector, manufacturer)
+}
+
+fn corrupt_index(env: &Env, contract_id: &Address, participant: &Address, index: Vec<u128>) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .instance()
+            .set(&("participant_wastes", participant.clone()), &index);
+    });
+}
+
+#[test]
+fn test_consistent_index_passes() {
+    let env = Env::default();
+    let (client, _, admin, collector, manufacturer) = setup(&env);
+
+    client.recycle_waste(&WasteType::Plastic, &1000, &collector, &0, &0);
+    let moved = client.recycle_waste(&WasteType::Metal, &2000, &collector, &0, &0);
+    client.transfer_waste_v2(&moved, &collector, &manufacturer, &0, &0);
+
+    assert!(client.verify_index_consistency(&admin, &collector));
+    assert!(client.verify_index_consistency(&admin, &manufacturer));
+}
+
+#[test]
+fn test_drift_detected_and_repaired() {
+    let env = Env::default();
+    let (client, contract_id, admin, collector, manufacturer) = setup(&env);
+
+    let kept = client.recycle_waste(&WasteType::Plastic, &1000, &collector, &0, &0);
+    let moved = client.recycle_waste(&WasteType::Metal, &2000, &collector, &0, &0);
+    client.transfer_waste_v2(&moved, &collector, &manufacturer, &0, &0);
+
+    // Index claims a waste now owned by someone else, a missing ID, and drops `kept`
+    corrupt_index(&env, &contract_id, &collector, vec![&env, moved, 999u128]);
+    assert!(!client.verify_index_consistency(&admin, &collector));
+
+    let repaired = client.repair_participant_index(&admin, &collector);
+    assert_eq!(repaired, vec![&env, kept]);
+    assert_eq!(client.get_participant_wastes_v2(&collector), vec![&env, kept]);
+    assert!(client.verify_index_consistency(&admin, &collector));
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_consistency_check_non_admin() {
+    let env = Env::default();
+    let (client, _, _, collector, _) = setup(&env);
+
+    client.verify_index_consistency(&collector, &collector);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_repair_non_admin() {
+    let env = Env::default();
+    let (client, _, _, collector, _) = setup(&env);
+
+    client.repair_participant_index(&collector, &collector);
+}
@@ -0,0 +1,58 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+fn reward_for(env: &Env, client: &ScavengerContractClient, submitter: &Address, verifier: &Address, waste_type: WasteType) -> u64 {
+    let material = client.submit_material(&waste_type, &2000, submitter, &String::from_str(env, "x"));
+    client.verify_material(&material.id, verifier);
+    client.get_waste_reward(&material.id).unwrap()
+}
+
+#[test]
+fn test_metal_earns_bonus_paper_does_not() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_infinite_recyclable_bonus(&admin, &50);
+    assert_eq!(client.get_infinite_recyclable_bonus(), 50);
+    assert_eq!(client.get_config().infinite_recyclable_bonus_pct, 50);
+
+    // 2kg metal: 100 points plus 50%
+    let metal = reward_for(&env, &client, &submitter, &verifier, WasteType::Metal);
+    assert_eq!(metal, 150);
+
+    // 2kg paper: 20 points, no bonus
+    let paper = reward_for(&env, &client, &submitter, &verifier, WasteType::Paper);
+    assert_eq!(paper, 20);
+    assert!(metal > paper);
+}
+
+#[test]
+fn test_no_bonus_by_default() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    assert_eq!(reward_for(&env, &client, &submitter, &verifier, WasteType::Metal), 100);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_bonus_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_infinite_recyclable_bonus(&submitter, &50);
+}
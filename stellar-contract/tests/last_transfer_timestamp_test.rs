@@ -0,0 +1,43 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_returns_latest_transfer_timestamp() {
+    let env = Env::default();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    assert_eq!(client.get_last_transfer_timestamp(&(waste_id as u64)), Some(1_000));
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+    assert_eq!(client.get_last_transfer_timestamp(&(waste_id as u64)), Some(5_000));
+}
+
+#[test]
+fn test_never_transferred_returns_none() {
+    let env = Env::default();
+    let (client, recycler, _, _) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+
+    assert_eq!(client.get_last_transfer_timestamp(&(waste_id as u64)), None);
+    assert_eq!(client.get_last_transfer_timestamp(&999), None);
+}
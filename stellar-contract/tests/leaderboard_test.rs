@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let alice = common::register(env, &client, ParticipantRole::Collector, symbol_short!("alice"));
+    let bob = common::register(env, &client, ParticipantRole::Collector, symbol_short!("bob"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, alice, bob, verifier)
+}
+
+fn submit_and_verify(
+    env: &Env,
+    client: &ScavengerContractClient,
+    submitter: &Address,
+    verifier: &Address,
+    weight: u64,
+) {
+    let material = client.submit_material(&WasteType::Paper, &weight, submitter, &String::from_str(env, "Paper"));
+    client.verify_material(&material.id, verifier);
+}
+
+#[test]
+fn test_top_recyclers_sorted_by_total_points() {
+    let env = Env::default();
+    let (client, _, alice, bob, verifier) = setup(&env);
+
+    submit_and_verify(&env, &client, &alice, &verifier, 2000);
+    submit_and_verify(&env, &client, &bob, &verifier, 5000);
+
+    let top = client.get_top_recyclers(&10);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top.get(0).unwrap(), (bob.clone(), 50));
+    assert_eq!(top.get(1).unwrap(), (alice.clone(), 20));
+
+    let limited = client.get_top_recyclers(&1);
+    assert_eq!(limited.len(), 1);
+    assert_eq!(limited.get(0).unwrap().0, bob);
+}
+
+#[test]
+fn test_season_leaderboard_only_counts_post_reset_points() {
+    let env = Env::default();
+    let (client, admin, alice, bob, verifier) = setup(&env);
+
+    // Season 1: bob leads
+    submit_and_verify(&env, &client, &alice, &verifier, 1000);
+    submit_and_verify(&env, &client, &bob, &verifier, 9000);
+
+    client.reset_season(&admin);
+    assert_eq!(client.get_season_leaderboard(&10).len(), 0);
+
+    // Season 2: only alice recycles
+    submit_and_verify(&env, &client, &alice, &verifier, 3000);
+
+    let season = client.get_season_leaderboard(&10);
+    assert_eq!(season.len(), 1);
+    assert_eq!(season.get(0).unwrap(), (alice.clone(), 30));
+
+    // All-time ranking still reflects both seasons
+    let all_time = client.get_top_recyclers(&10);
+    assert_eq!(all_time.get(0).unwrap(), (bob, 90));
+    assert_eq!(all_time.get(1).unwrap(), (alice, 40));
+}
+
+#[test]
+fn test_leaderboards_empty_without_points() {
+    let env = Env::default();
+    let (client, _, _, _, _) = setup(&env);
+
+    assert_eq!(client.get_top_recyclers(&10).len(), 0);
+    assert_eq!(client.get_season_leaderboard(&10).len(), 0);
+}
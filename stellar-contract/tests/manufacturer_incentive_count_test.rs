@@ -0,0 +1,40 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, manufacturer)
+}
+
+#[test]
+fn test_counts_all_created_incentives() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    client.create_incentive(&manufacturer, &WasteType::Metal, &20, &2000);
+    let third = client.create_incentive(&manufacturer, &WasteType::Glass, &30, &3000);
+    // Deactivated incentives still count towards the total created
+    client.deactivate_incentive(&third.id, &manufacturer);
+
+    assert_eq!(client.get_manufacturer_incentive_count(&manufacturer), 3);
+}
+
+#[test]
+fn test_count_is_per_manufacturer() {
+    let env = Env::default();
+    let (client, manufacturer) = setup(&env);
+
+    let other = common::register(&env, &client, ParticipantRole::Manufacturer, symbol_short!("other"));
+    client.create_incentive(&other, &WasteType::Paper, &10, &1000);
+
+    assert_eq!(client.get_manufacturer_incentive_count(&manufacturer), 0);
+    assert_eq!(client.get_manufacturer_incentive_count(&other), 1);
+}
@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+
+    (client, submitter)
+}
+
+#[test]
+fn test_only_in_band_materials_returned() {
+    let env = Env::default();
+    let (client, submitter) = setup(&env);
+    let desc = String::from_str(&env, "Band");
+
+    let light = client.submit_material(&WasteType::Paper, &500, &submitter, &desc);
+    let lower = client.submit_material(&WasteType::Paper, &1000, &submitter, &desc);
+    let middle = client.submit_material(&WasteType::Metal, &2500, &submitter, &desc);
+    let upper = client.submit_material(&WasteType::Glass, &5000, &submitter, &desc);
+    let heavy = client.submit_material(&WasteType::Glass, &9000, &submitter, &desc);
+
+    let ids = client.get_materials_in_weight_range(&submitter, &1000, &5000);
+    assert_eq!(ids, vec![&env, lower.id, middle.id, upper.id]);
+    assert!(!ids.contains(light.id));
+    assert!(!ids.contains(heavy.id));
+}
+
+#[test]
+fn test_excludes_other_submitters() {
+    let env = Env::default();
+    let (client, submitter) = setup(&env);
+    let other = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("other"));
+    let desc = String::from_str(&env, "Band");
+
+    let own = client.submit_material(&WasteType::Paper, &1500, &submitter, &desc);
+    client.submit_material(&WasteType::Paper, &1500, &other, &desc);
+
+    assert_eq!(client.get_materials_in_weight_range(&submitter, &0, &u64::MAX), vec![&env, own.id]);
+}
+
+#[test]
+fn test_inverted_range_returns_empty() {
+    let env = Env::default();
+    let (client, submitter) = setup(&env);
+
+    client.submit_material(&WasteType::Paper, &1500, &submitter, &String::from_str(&env, "Band"));
+
+    assert_eq!(client.get_materials_in_weight_range(&submitter, &2000, &1000).len(), 0);
+}
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let admin = common::init_admin(env, &client);
+
+    (client, admin, contract_id)
+}
+
+fn index_len(env: &Env, contract_id: &Address) -> u32 {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .instance()
+            .get::<_, Vec<Address>>(&("participant_index",))
+            .map(|index| index.len())
+            .unwrap_or(0)
+    })
+}
+
+#[test]
+#[should_panic(expected = "Registration closed")]
+fn test_third_registration_panics_at_cap_of_two() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    client.set_max_participants(&admin, &2);
+    assert_eq!(client.get_max_participants(), 2);
+    assert_eq!(client.get_config().max_participants, 2);
+
+    client.register_participant(&Address::generate(&env), &ParticipantRole::Recycler, &symbol_short!("a"), &0, &0);
+    client.register_participant(&Address::generate(&env), &ParticipantRole::Collector, &symbol_short!("b"), &0, &0);
+
+    client.register_participant(&Address::generate(&env), &ParticipantRole::Recycler, &symbol_short!("c"), &0, &0);
+}
+
+#[test]
+fn test_deregistration_frees_a_slot() {
+    let env = Env::default();
+    let (client, admin, contract_id) = setup(&env);
+
+    client.set_max_participants(&admin, &2);
+    let first = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("a"));
+    client.register_participant(&Address::generate(&env), &ParticipantRole::Collector, &symbol_short!("b"), &0, &0);
+
+    client.deregister_participant(&first);
+    client.register_participant(&first, &ParticipantRole::Recycler, &symbol_short!("a"), &0, &0);
+
+    assert_eq!(index_len(&env, &contract_id), 2);
+}
+
+#[test]
+fn test_zero_cap_disables_limit() {
+    let env = Env::default();
+    let (client, admin, contract_id) = setup(&env);
+
+    client.set_max_participants(&admin, &0);
+    for _ in 0..3 {
+        client.register_participant(&Address::generate(&env), &ParticipantRole::Recycler, &symbol_short!("x"), &0, &0);
+    }
+
+    assert_eq!(index_len(&env, &contract_id), 3);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_max_participants_non_admin() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    client.set_max_participants(&Address::generate(&env), &2);
+}
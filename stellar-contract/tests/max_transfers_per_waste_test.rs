@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, admin, recycler, collector, manufacturer)
+}
+
+#[test]
+#[should_panic(expected = "Transfer limit reached")]
+fn test_third_transfer_panics_at_cap_of_two() {
+    let env = Env::default();
+    let (client, admin, recycler, collector, manufacturer) = setup(&env);
+
+    client.set_max_transfers_per_waste(&admin, &2);
+    assert_eq!(client.get_max_transfers_per_waste(), 2);
+    assert_eq!(client.get_config().max_transfers_per_waste, 2);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+
+    client.transfer_waste_v2(&waste_id, &manufacturer, &collector, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Transfer limit reached")]
+fn test_cap_blocks_otherwise_valid_transfer() {
+    let env = Env::default();
+    let (client, admin, recycler, collector, manufacturer) = setup(&env);
+
+    client.set_max_transfers_per_waste(&admin, &1);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+}
+
+#[test]
+fn test_zero_cap_is_unlimited() {
+    let env = Env::default();
+    let (client, _, recycler, collector, manufacturer) = setup(&env);
+
+    assert_eq!(client.get_max_transfers_per_waste(), 0);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+
+    assert_eq!(client.get_waste_transfer_history_v2(&waste_id).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_max_transfers_non_admin() {
+    let env = Env::default();
+    let (client, _, recycler, _, _) = setup(&env);
+
+    client.set_max_transfers_per_waste(&recycler, &2);
+}
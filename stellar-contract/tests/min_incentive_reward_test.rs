@@ -0,0 +1,67 @@
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, admin, manufacturer)
+}
+
+#[test]
+fn test_create_incentive_at_floor_succeeds() {
+    let env = Env::default();
+    let (client, admin, manufacturer) = setup(&env);
+
+    client.set_min_incentive_reward(&admin, &10);
+    assert_eq!(client.get_min_incentive_reward(), 10);
+    assert_eq!(client.get_config().min_incentive_reward, 10);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &10, &1000);
+    assert_eq!(incentive.reward_points, 10);
+}
+
+#[test]
+#[should_panic(expected = "Reward below minimum")]
+fn test_create_incentive_below_floor_panics() {
+    let env = Env::default();
+    let (client, admin, manufacturer) = setup(&env);
+
+    client.set_min_incentive_reward(&admin, &10);
+    client.create_incentive(&manufacturer, &WasteType::Plastic, &9, &1000);
+}
+
+#[test]
+#[should_panic(expected = "Reward below minimum")]
+fn test_update_incentive_below_floor_panics() {
+    let env = Env::default();
+    let (client, admin, manufacturer) = setup(&env);
+
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &20, &1000);
+    client.set_min_incentive_reward(&admin, &10);
+
+    client.update_incentive(&incentive.id, &5, &1000);
+}
+
+#[test]
+fn test_no_floor_by_default() {
+    let env = Env::default();
+    let (client, _, manufacturer) = setup(&env);
+
+    assert_eq!(client.get_min_incentive_reward(), 0);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Plastic, &1, &1000);
+    assert_eq!(incentive.reward_points, 1);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_min_incentive_reward_non_admin_fails() {
+    let env = Env::default();
+    let (client, _, manufacturer) = setup(&env);
+
+    client.set_min_incentive_reward(&manufacturer, &10);
+}
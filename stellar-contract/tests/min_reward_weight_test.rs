@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+#[test]
+fn test_below_threshold_verified_but_earns_nothing() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_min_reward_weight(&admin, &2000);
+    assert_eq!(client.get_min_reward_weight(), 2000);
+    assert_eq!(client.get_config().min_reward_weight, 2000);
+
+    let desc = String::from_str(&env, "Paper");
+    let light = client.submit_material(&WasteType::Paper, &1999, &submitter, &desc);
+    let exact = client.submit_material(&WasteType::Paper, &2000, &submitter, &desc);
+
+    let light = client.verify_material(&light.id, &verifier);
+    assert!(light.verified);
+    assert_eq!(light.reward_points_earned, 0);
+
+    let exact = client.verify_material(&exact.id, &verifier);
+    assert!(exact.verified);
+    assert_eq!(exact.reward_points_earned, 20);
+
+    let stats = client.get_stats(&submitter).unwrap();
+    assert_eq!(stats.verified_submissions, 2);
+    assert_eq!(stats.total_points, 20);
+}
+
+#[test]
+fn test_no_minimum_by_default() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    assert_eq!(client.get_min_reward_weight(), 0);
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &String::from_str(&env, "Paper"));
+    assert_eq!(client.verify_material(&material.id, &verifier).reward_points_earned, 10);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_min_reward_weight_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_min_reward_weight(&submitter, &2000);
+}
@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+#[test]
+fn test_rewards_start_after_threshold() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_min_submissions_for_rewards(&admin, &3);
+    assert_eq!(client.get_min_submissions_for_rewards(), 3);
+    assert_eq!(client.get_config().min_submissions_for_rewards, 3);
+
+    let desc = String::from_str(&env, "Paper");
+    let mut earned = Vec::new();
+    for _ in 0..4 {
+        let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &desc);
+        let verified = client.verify_material(&material.id, &verifier);
+        earned.push(verified.reward_points_earned);
+    }
+
+    assert_eq!(earned, [0, 0, 10, 10]);
+
+    let stats = client.get_stats(&submitter).unwrap();
+    assert_eq!(stats.verified_submissions, 4);
+    assert_eq!(stats.total_points, 20);
+    assert_eq!(client.get_participant(&submitter).unwrap().total_tokens_earned, 20);
+}
+
+#[test]
+fn test_threshold_applies_to_batch_verification() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_min_submissions_for_rewards(&admin, &3);
+
+    let desc = String::from_str(&env, "Paper");
+    let m1 = client.submit_material(&WasteType::Paper, &1000, &submitter, &desc);
+    let m2 = client.submit_material(&WasteType::Paper, &1000, &submitter, &desc);
+    let m3 = client.submit_material(&WasteType::Paper, &1000, &submitter, &desc);
+
+    let results = client.verify_materials_batch(&vec![&env, m1.id, m2.id, m3.id], &verifier);
+    assert_eq!(results.get(0).unwrap().reward_points_earned, 0);
+    assert_eq!(results.get(1).unwrap().reward_points_earned, 0);
+    assert_eq!(results.get(2).unwrap().reward_points_earned, 10);
+    assert_eq!(client.get_stats(&submitter).unwrap().total_points, 10);
+}
+
+#[test]
+fn test_no_threshold_by_default() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    assert_eq!(client.get_min_submissions_for_rewards(), 0);
+
+    let material = client.submit_material(&WasteType::Paper, &1000, &submitter, &String::from_str(&env, "Paper"));
+    let verified = client.verify_material(&material.id, &verifier);
+    assert_eq!(verified.reward_points_earned, 10);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_min_submissions_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_min_submissions_for_rewards(&submitter, &3);
+}
@@ -0,0 +1,45 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+#[test]
+#[should_panic(expected = "Participant not registered")]
+fn test_update_role_unregistered_address() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    client.update_role(&Address::generate(&env), &ParticipantRole::Collector);
+}
+
+#[test]
+#[should_panic(expected = "Participant not registered")]
+fn test_update_role_after_deregistration() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let participant = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("p"));
+    client.deregister_participant(&participant);
+
+    client.update_role(&participant, &ParticipantRole::Collector);
+}
+
+#[test]
+#[should_panic(expected = "Participant not registered")]
+fn test_update_location_unregistered_address() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    client.update_location(&Address::generate(&env), &1, &1);
+}
+
+#[test]
+#[should_panic(expected = "Participant not registered")]
+fn test_create_incentive_unregistered_address() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    client.create_incentive(&Address::generate(&env), &WasteType::Metal, &30, &5000);
+}
@@ -0,0 +1,34 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("greenco"));
+
+    (client, recycler)
+}
+
+#[test]
+fn test_name_preserved_on_registration() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    assert_eq!(client.get_participant(&recycler).unwrap().name, symbol_short!("greenco"));
+    assert_eq!(client.get_participant_info(&recycler).unwrap().participant.name, symbol_short!("greenco"));
+}
+
+#[test]
+fn test_name_preserved_across_updates() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    client.update_role(&recycler, &ParticipantRole::Collector);
+    client.update_location(&recycler, &1_000_000, &2_000_000);
+
+    assert_eq!(client.get_participant(&recycler).unwrap().name, symbol_short!("greenco"));
+}
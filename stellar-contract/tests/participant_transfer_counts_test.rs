@@ -0,0 +1,41 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_sent_and_received_counts() {
+    let env = Env::default();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    let second = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+
+    client.transfer_waste_v2(&first, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&first, &collector, &manufacturer, &0, &0);
+    client.transfer_waste_v2(&second, &recycler, &manufacturer, &0, &0);
+
+    assert_eq!(client.get_participant_transfer_counts(&recycler), (2, 0));
+    assert_eq!(client.get_participant_transfer_counts(&collector), (1, 1));
+    assert_eq!(client.get_participant_transfer_counts(&manufacturer), (0, 2));
+}
+
+#[test]
+fn test_no_transfers_returns_zero() {
+    let env = Env::default();
+    let (client, recycler, _, _) = setup(&env);
+
+    assert_eq!(client.get_participant_transfer_counts(&recycler), (0, 0));
+}
@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContract, ScavengerContractClient, Waste, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ScavengerContract);
+    let client = ScavengerContractClient::new(env, &contract_id);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, recycler, contract_id)
+}
+
+#[test]
+fn test_returns_full_records_for_owner() {
+    let env = Env::default();
+    let (client, recycler, _) = setup(&env);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    let second = client.recycle_waste(&WasteType::Metal, &2500, &recycler, &0, &0);
+
+    let wastes = client.get_participant_wastes_full(&recycler);
+    assert_eq!(wastes.len(), 2);
+
+    let first_waste = wastes.get(0).unwrap();
+    assert_eq!(first_waste.waste_id, first);
+    assert_eq!(first_waste.weight, 1000);
+    assert_eq!(first_waste.current_owner, recycler);
+
+    let second_waste = wastes.get(1).unwrap();
+    assert_eq!(second_waste.waste_id, second);
+    assert_eq!(second_waste.waste_type, WasteType::Metal);
+    assert_eq!(second_waste.current_owner, recycler);
+}
+
+#[test]
+fn test_missing_records_skipped() {
+    let env = Env::default();
+    let (client, recycler, contract_id) = setup(&env);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    let second = client.recycle_waste(&WasteType::Metal, &2500, &recycler, &0, &0);
+
+    env.as_contract(&contract_id, || {
+        env.storage().instance().remove(&("waste_v2", first));
+    });
+
+    let wastes = client.get_participant_wastes_full(&recycler);
+    assert_eq!(wastes.len(), 1);
+    assert_eq!(wastes.get(0).unwrap().waste_id, second);
+}
+
+#[test]
+fn test_no_wastes_returns_empty() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    let wastes: soroban_sdk::Vec<Waste> = client.get_participant_wastes_full(&Address::generate(&env));
+    assert!(wastes.is_empty());
+}
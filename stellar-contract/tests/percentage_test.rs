@@ -1,4 +1,3 @@
-use soroban_sdk::symbol_short;
 #![cfg(test)]
 
 use soroban_sdk::{testutils::Address as _, Address, Env};
@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let first = common::register(env, &client, ParticipantRole::Collector, symbol_short!("a"));
+    let second = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("b"));
+
+    (client, first, second)
+}
+
+#[test]
+fn test_feed_is_most_recent_first_across_participants() {
+    let env = Env::default();
+    let (client, first, second) = setup(&env);
+
+    let a = client.submit_material(&WasteType::Paper, &1000, &first, &String::from_str(&env, "a"));
+    let b = client.submit_material(&WasteType::Metal, &1000, &second, &String::from_str(&env, "b"));
+    let c = client.submit_material(&WasteType::Glass, &1000, &first, &String::from_str(&env, "c"));
+
+    assert_eq!(client.get_recent_submissions(&10), vec![&env, c.id, b.id, a.id]);
+    assert_eq!(client.get_recent_submissions(&2), vec![&env, c.id, b.id]);
+}
+
+#[test]
+fn test_feed_keeps_only_latest_within_cap() {
+    let env = Env::default();
+    let (client, first, _) = setup(&env);
+
+    let mut last_id = 0;
+    for _ in 0..25 {
+        last_id = client.submit_material(&WasteType::Paper, &1000, &first, &String::from_str(&env, "x")).id;
+    }
+
+    let feed = client.get_recent_submissions(&100);
+    assert_eq!(feed.len(), 20);
+    assert_eq!(feed.get(0).unwrap(), last_id);
+    assert_eq!(feed.get(19).unwrap(), last_id - 19);
+}
+
+#[test]
+fn test_batch_submissions_included() {
+    let env = Env::default();
+    let (client, first, _) = setup(&env);
+
+    let materials = vec![
+        &env,
+        (WasteType::Paper, 1000u64, String::from_str(&env, "a")),
+        (WasteType::Glass, 1000u64, String::from_str(&env, "b")),
+    ];
+    let results = client.submit_materials_batch(&materials, &first);
+
+    assert_eq!(
+        client.get_recent_submissions(&5),
+        vec![&env, results.get(1).unwrap().id, results.get(0).unwrap().id]
+    );
+}
+
+#[test]
+fn test_empty_feed() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert!(client.get_recent_submissions(&5).is_empty());
+}
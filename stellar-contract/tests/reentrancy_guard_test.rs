@@ -280,7 +280,7 @@ fn test_token_address_management() {
 }
 
 #[test]
-#[should_panic(expected = "Unauthorized: caller is not admin")]
+#[should_panic(expected = "Caller is not the contract admin")]
 fn test_set_token_address_non_admin() {
     let env = Env::default();
     env.mock_all_auths();
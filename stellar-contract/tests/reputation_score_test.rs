@@ -0,0 +1,47 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, submitter, verifier)
+}
+
+#[test]
+fn test_score_matches_formula() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup(&env);
+    let desc = String::from_str(&env, "Rep");
+
+    // 4 submissions totalling 10.5kg, 3 of them verified
+    let m1 = client.submit_material(&WasteType::Paper, &2000, &submitter, &desc);
+    let m2 = client.submit_material(&WasteType::Metal, &3000, &submitter, &desc);
+    let m3 = client.submit_material(&WasteType::Glass, &4000, &submitter, &desc);
+    client.submit_material(&WasteType::Plastic, &1500, &submitter, &desc);
+
+    client.verify_material(&m1.id, &verifier);
+    client.verify_material(&m2.id, &verifier);
+    client.verify_material(&m3.id, &verifier);
+
+    let stats = client.get_stats(&submitter).unwrap();
+    let expected = stats.verified_submissions * 10 + stats.verification_rate() + stats.total_weight / 1000;
+
+    // 3 * 10 + 75% + 10kg
+    assert_eq!(expected, 115);
+    assert_eq!(client.get_reputation_score(&submitter), expected);
+}
+
+#[test]
+fn test_unknown_participant_scores_zero() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert_eq!(client.get_reputation_score(&Address::generate(&env)), 0);
+}
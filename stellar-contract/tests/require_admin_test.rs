@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::ScavengerContractClient;
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+
+    (client, admin)
+}
+
+#[test]
+fn test_admin_succeeds_across_admin_functions() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let token = Address::generate(&env);
+
+    client.set_percentages(&admin, &10, &40);
+    client.set_token_address(&admin, &token);
+
+    assert_eq!(client.get_collector_percentage(), Some(10));
+    assert_eq!(client.get_owner_percentage(), Some(40));
+    assert_eq!(client.get_token_address(), Some(token));
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_non_admin_set_percentages_panics() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.set_percentages(&Address::generate(&env), &10, &40);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_non_admin_set_token_address_panics() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    client.set_token_address(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Admin not initialized")]
+fn test_admin_function_before_initialization_panics() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    client.set_percentages(&Address::generate(&env), &10, &40);
+}
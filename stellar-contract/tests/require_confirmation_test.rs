@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let confirmer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("conf"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, confirmer, verifier)
+}
+
+#[test]
+#[should_panic(expected = "Waste not confirmed")]
+fn test_verify_unconfirmed_panics_when_required() {
+    let env = Env::default();
+    let (client, admin, submitter, _, verifier) = setup(&env);
+
+    client.set_require_confirmation(&admin, &true);
+    assert!(client.get_require_confirmation());
+    assert!(client.get_config().require_confirmation_to_verify);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.verify_material(&material.id, &verifier);
+}
+
+#[test]
+fn test_verify_confirmed_succeeds_when_required() {
+    let env = Env::default();
+    let (client, admin, submitter, confirmer, verifier) = setup(&env);
+
+    client.set_require_confirmation(&admin, &true);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.confirm_material(&material.id, &confirmer);
+
+    let verified = client.verify_material(&material.id, &verifier);
+    assert!(verified.verified);
+}
+
+#[test]
+fn test_batch_skips_unconfirmed_when_required() {
+    let env = Env::default();
+    let (client, admin, submitter, confirmer, verifier) = setup(&env);
+
+    client.set_require_confirmation(&admin, &true);
+
+    let confirmed = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    let unconfirmed = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.confirm_material(&confirmed.id, &confirmer);
+
+    let results = client.verify_materials_batch(&vec![&env, confirmed.id, unconfirmed.id], &verifier);
+    assert_eq!(results.len(), 1);
+    assert!(client.get_material(&confirmed.id).unwrap().verified);
+    assert!(!client.get_material(&unconfirmed.id).unwrap().verified);
+}
+
+#[test]
+fn test_confirmation_not_required_by_default() {
+    let env = Env::default();
+    let (client, _, submitter, _, verifier) = setup(&env);
+
+    assert!(!client.get_require_confirmation());
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    assert!(client.verify_material(&material.id, &verifier).verified);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_require_confirmation_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _, _) = setup(&env);
+
+    client.set_require_confirmation(&submitter, &true);
+}
@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Events, Address, Env, IntoVal, String, Symbol};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+fn submit_and_verify(
+    env: &Env,
+    client: &ScavengerContractClient,
+    submitter: &Address,
+    verifier: &Address,
+    weight: u64,
+) {
+    let material = client.submit_material(&WasteType::Metal, &weight, submitter, &String::from_str(env, "Cans"));
+    client.verify_material(&material.id, verifier);
+}
+
+#[test]
+fn test_reset_season_moves_points_to_previous_season() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    submit_and_verify(&env, &client, &submitter, &verifier, 2000);
+    submit_and_verify(&env, &client, &submitter, &verifier, 3000);
+
+    let before = client.get_stats(&submitter).unwrap();
+    assert_eq!(before.season_points, 250);
+
+    client.reset_season(&admin);
+
+    let after = client.get_stats(&submitter).unwrap();
+    assert_eq!(after.season_points, 0);
+    assert_eq!(after.previous_season_points, 250);
+    // All-time points are untouched
+    assert_eq!(after.total_points, 250);
+}
+
+#[test]
+fn test_points_after_reset_count_toward_new_season() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    submit_and_verify(&env, &client, &submitter, &verifier, 2000);
+    client.reset_season(&admin);
+    submit_and_verify(&env, &client, &submitter, &verifier, 1000);
+
+    let stats = client.get_stats(&submitter).unwrap();
+    assert_eq!(stats.season_points, 50);
+    assert_eq!(stats.previous_season_points, 100);
+    assert_eq!(stats.total_points, 150);
+}
+
+#[test]
+fn test_reset_season_emits_event() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    submit_and_verify(&env, &client, &submitter, &verifier, 2000);
+    client.reset_season(&admin);
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: soroban_sdk::Vec<soroban_sdk::Val> =
+        (Symbol::new(&env, "season"), Symbol::new(&env, "reset")).into_val(&env);
+    assert_eq!(topics, expected);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_reset_season_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.reset_season(&submitter);
+}
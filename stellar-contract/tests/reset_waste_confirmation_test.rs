@@ -1,4 +1,3 @@
-use soroban_sdk::symbol_short;
 #![cfg(test)]
 
 use soroban_sdk::{testutils::Address as _, Address, Env};
@@ -35,6 +34,15 @@ fn test_reset_waste_confirmation() {
         &-93_000_000,
     );
 
+    // Register confirmer as a third-party manufacturer
+    client.register_participant(
+        &confirmer,
+        &ParticipantRole::Manufacturer,
+        &soroban_sdk::symbol_short!("Manuf"),
+        &45_000_000,
+        &-93_000_000,
+    );
+
     // Register waste
     let waste_id = client.recycle_waste(
         &WasteType::Plastic,
@@ -79,6 +87,15 @@ fn test_reset_waste_confirmation_non_owner() {
         &-93_000_000,
     );
 
+    // Register confirmer as a third-party manufacturer
+    client.register_participant(
+        &confirmer,
+        &ParticipantRole::Manufacturer,
+        &soroban_sdk::symbol_short!("Manuf"),
+        &45_000_000,
+        &-93_000_000,
+    );
+
     // Register waste
     let waste_id = client.recycle_waste(
         &WasteType::Plastic,
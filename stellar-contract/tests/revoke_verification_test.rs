@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("Sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("Ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+#[test]
+fn test_revoke_within_window_succeeds() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_verification_revoke_window(&admin, &3_600);
+    assert_eq!(client.get_config().verification_revoke_window, 3_600);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    let verified = client.verify_material(&material.id, &verifier);
+    assert_eq!(verified.verified_at, 1_000);
+    assert_eq!(client.get_total_points_awarded(), 100);
+    assert_eq!(client.get_participant(&submitter).unwrap().total_tokens_earned, 100);
+
+    env.ledger().with_mut(|li| li.timestamp = 4_600);
+    let revoked = client.revoke_verification(&admin, &material.id);
+
+    assert!(!revoked.verified);
+    assert_eq!(revoked.verified_at, 0);
+    assert!(!client.get_material(&material.id).unwrap().verified);
+
+    let stats = client.get_stats(&submitter).unwrap();
+    assert_eq!(stats.verified_submissions, 0);
+    assert_eq!(stats.total_points, 0);
+    assert_eq!(client.get_total_points_awarded(), 0);
+    // Tokens already distributed stay with their recipients
+    assert_eq!(client.get_participant(&submitter).unwrap().total_tokens_earned, 100);
+}
+
+#[test]
+#[should_panic(expected = "Revoke window elapsed")]
+fn test_revoke_after_window_panics() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_verification_revoke_window(&admin, &3_600);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &verifier);
+
+    env.ledger().with_mut(|li| li.timestamp = 4_601);
+    client.revoke_verification(&admin, &material.id);
+}
+
+#[test]
+fn test_revoke_without_window_has_no_deadline() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.verify_material(&material.id, &verifier);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+    let revoked = client.revoke_verification(&admin, &material.id);
+    assert!(!revoked.verified);
+}
+
+#[test]
+#[should_panic(expected = "Material not verified")]
+fn test_revoke_unverified_material_panics() {
+    let env = Env::default();
+    let (client, admin, submitter, _) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.revoke_verification(&admin, &material.id);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_revoke_by_non_admin_panics() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    client.verify_material(&material.id, &verifier);
+    client.revoke_verification(&verifier, &material.id);
+}
+
+#[test]
+fn test_revoke_with_collector_in_chain_keeps_distributed_tokens() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+    let collector = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("Col"));
+
+    // Metal 2kg = 100 points, split between the collector hop and the owner
+    let desc = String::from_str(&env, "Cans");
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &desc);
+    client.transfer_waste(&material.id, &submitter, &collector, &desc);
+    client.verify_material(&material.id, &verifier);
+
+    let collector_tokens = client.get_participant(&collector).unwrap().total_tokens_earned;
+    let submitter_tokens = client.get_participant(&submitter).unwrap().total_tokens_earned;
+    assert!(collector_tokens > 0);
+    assert_eq!(client.get_total_points_awarded(), 100);
+
+    client.revoke_verification(&admin, &material.id);
+
+    assert_eq!(client.get_total_points_awarded(), 0);
+    assert_eq!(client.get_stats(&collector).unwrap().verified_submissions, 0);
+    assert_eq!(client.get_participant(&collector).unwrap().total_tokens_earned, collector_tokens);
+    assert_eq!(client.get_participant(&submitter).unwrap().total_tokens_earned, submitter_tokens);
+}
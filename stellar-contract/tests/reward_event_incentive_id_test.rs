@@ -0,0 +1,54 @@
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Events, Address, Env, IntoVal, String, TryIntoVal, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, manufacturer, collector, recycler)
+}
+
+#[test]
+fn test_incentive_reward_event_carries_incentive_id() {
+    let env = Env::default();
+    let (client, manufacturer, collector, recycler) = setup(&env);
+
+    // Create a decoy incentive first so the matching one doesn't have ID 1
+    client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+    let incentive = client.create_incentive(&manufacturer, &WasteType::Metal, &100, &1000);
+
+    let material = client.submit_material(&WasteType::Metal, &5000, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+
+    let reward = client.claim_incentive_reward(&incentive.id, &material.id, &collector);
+    assert_eq!(reward, 500);
+
+    let event = env.events().all().last().unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> = (symbol_short!("rewarded"), collector.clone()).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    let event_data: (u128, u64, u64) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data, (500, material.id, incentive.id));
+}
+
+#[test]
+fn test_base_reward_event_has_zero_incentive_id() {
+    let env = Env::default();
+    let (client, _, collector, recycler) = setup(&env);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+
+    let event = env.events().all().last().unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> = (symbol_short!("rewarded"), collector.clone()).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    let event_data: (u128, u64, u64) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data.1, material.id);
+    assert_eq!(event_data.2, 0);
+}
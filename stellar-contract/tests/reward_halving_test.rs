@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+fn submit_and_verify(env: &Env, client: &ScavengerContractClient, submitter: &Address, verifier: &Address) -> u64 {
+    let material = client.submit_material(&WasteType::Metal, &2000, submitter, &String::from_str(env, "Cans"));
+    client.verify_material(&material.id, verifier);
+    client.get_waste_reward(&material.id).unwrap()
+}
+
+#[test]
+fn test_rewards_halve_after_each_interval() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_reward_halving_interval(&admin, &2);
+    assert_eq!(client.get_reward_halving_interval(), 2);
+    assert_eq!(client.get_config().reward_halving_interval, 2);
+
+    assert_eq!(submit_and_verify(&env, &client, &submitter, &verifier), 100);
+    assert_eq!(submit_and_verify(&env, &client, &submitter, &verifier), 100);
+
+    // Crossing the first boundary halves rewards
+    assert_eq!(submit_and_verify(&env, &client, &submitter, &verifier), 50);
+    assert_eq!(submit_and_verify(&env, &client, &submitter, &verifier), 50);
+
+    // And the second halves them again
+    assert_eq!(submit_and_verify(&env, &client, &submitter, &verifier), 25);
+    assert_eq!(client.get_verification_count(), 5);
+}
+
+#[test]
+fn test_batch_verifications_count_towards_halving() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_reward_halving_interval(&admin, &1);
+
+    let first = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    let second = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_materials_batch(&vec![&env, first.id, second.id], &verifier);
+
+    assert_eq!(client.get_waste_reward(&first.id), Some(100));
+    assert_eq!(client.get_waste_reward(&second.id), Some(50));
+}
+
+#[test]
+fn test_halving_disabled_by_default() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    assert_eq!(client.get_reward_halving_interval(), 0);
+    for _ in 0..3 {
+        assert_eq!(submit_and_verify(&env, &client, &submitter, &verifier), 100);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_halving_interval_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_reward_halving_interval(&submitter, &2);
+}
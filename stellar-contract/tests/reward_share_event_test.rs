@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::Events,
+    Address, Env, IntoVal, String, TryIntoVal, Val, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, WasteType};
+
+/// Collect (recipient, amount) from every reward share event in emission order
+fn share_events(env: &Env) -> std::vec::Vec<(Address, u128)> {
+    let share_topic: Val = symbol_short!("share").into_val(env);
+    let reward_topic: Val = symbol_short!("reward").into_val(env);
+
+    env.events()
+        .all()
+        .iter()
+        .filter(|event| {
+            event.1.len() == 3
+                && event.1.get(0).unwrap().shallow_eq(&reward_topic)
+                && event.1.get(1).unwrap().shallow_eq(&share_topic)
+        })
+        .map(|event| {
+            let recipient: Address = event.1.get(2).unwrap().try_into_val(env).unwrap();
+            let (amount, _waste_id): (u128, u64) = event.2.try_into_val(env).unwrap();
+            (recipient, amount)
+        })
+        .collect()
+}
+
+#[test]
+fn test_two_hop_chain_emits_share_per_recipient() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let admin = common::init_admin(&env, &client);
+    client.set_percentages(&admin, &10, &30);
+
+    let recycler = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Rec"));
+    let submitter = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("Sub"));
+    let collector1 = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("Col1"));
+    let collector2 = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("Col2"));
+
+    // Metal: 2kg * 5 multiplier * 10 = 100 points
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "test"));
+    client.transfer_waste(&material.id, &submitter, &collector1, &String::from_str(&env, "t1"));
+    client.transfer_waste(&material.id, &collector1, &collector2, &String::from_str(&env, "t2"));
+
+    client.verify_material(&material.id, &recycler);
+
+    let shares = share_events(&env);
+    let recipients: Vec<Address> = Vec::from_array(
+        &env,
+        [collector1.clone(), collector2.clone(), collector2.clone(), collector2.clone()],
+    );
+    assert_eq!(shares.len() as u32, recipients.len());
+    for (i, (recipient, _)) in shares.iter().enumerate() {
+        assert_eq!(*recipient, recipients.get(i as u32).unwrap());
+    }
+
+    let total: u128 = shares.iter().map(|(_, amount)| amount).sum();
+    assert_eq!(total, 100);
+    assert_eq!(shares[0].1, 10);
+    assert_eq!(shares[1].1, 10);
+}
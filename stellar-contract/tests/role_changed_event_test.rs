@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::Events,
+    Address, Env, IntoVal, Symbol, TryIntoVal, Val, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let participant = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("user"));
+
+    (client, participant)
+}
+
+fn role_changed_topics(env: &Env) -> Vec<Val> {
+    (Symbol::new(env, "participant"), Symbol::new(env, "role_changed")).into_val(env)
+}
+
+#[test]
+fn test_role_change_emits_old_and_new_roles() {
+    let env = Env::default();
+    let (client, participant) = setup(&env);
+
+    client.update_role(&participant, &ParticipantRole::Collector);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, role_changed_topics(&env));
+
+    let (address, old_role, new_role): (Address, ParticipantRole, ParticipantRole) =
+        data.try_into_val(&env).unwrap();
+    assert_eq!(address, participant);
+    assert_eq!(old_role, ParticipantRole::Recycler);
+    assert_eq!(new_role, ParticipantRole::Collector);
+}
+
+#[test]
+fn test_same_role_update_emits_no_event() {
+    let env = Env::default();
+    let (client, participant) = setup(&env);
+
+    client.update_role(&participant, &ParticipantRole::Recycler);
+
+    let expected = role_changed_topics(&env);
+    assert!(!env.events().all().iter().any(|(_, topics, _)| topics == expected));
+}
@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, String, TryIntoVal, Val, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, RoundingSink, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    // 33% owner / 67% submitter of 70 points floors to 23 + 46, leaving 1 point of rounding
+    client.set_percentages(&admin, &10, &33);
+
+    (client, admin, submitter, verifier)
+}
+
+/// Submit and verify 7kg of paper (70 points)
+fn verify_paper(env: &Env, client: &ScavengerContractClient, submitter: &Address, verifier: &Address) {
+    let material = client.submit_material(&WasteType::Paper, &7000, submitter, &String::from_str(env, "Paper"));
+    client.verify_material(&material.id, verifier);
+}
+
+fn tokens_earned(client: &ScavengerContractClient, participant: &Address) -> u128 {
+    client.get_participant(participant).unwrap().total_tokens_earned
+}
+
+fn supply(client: &ScavengerContractClient) -> u128 {
+    client.get_supply_chain_stats().2
+}
+
+/// Collect the amounts of all reward events emitted to `recipient`
+fn rewards_to(env: &Env, recipient: &Address) -> std::vec::Vec<u128> {
+    let expected_topics: Vec<Val> = (symbol_short!("rewarded"), recipient.clone()).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == expected_topics)
+        .map(|(_, _, data)| {
+            let (amount, _, _): (u128, u64, u64) = data.try_into_val(env).unwrap();
+            amount
+        })
+        .collect()
+}
+
+#[test]
+fn test_default_sink_is_recycler() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    assert_eq!(client.get_rounding_sink(), RoundingSink::Recycler);
+    assert_eq!(client.get_config().rounding_sink, RoundingSink::Recycler);
+
+    verify_paper(&env, &client, &submitter, &verifier);
+
+    assert_eq!(tokens_earned(&client, &submitter), 70);
+    assert_eq!(supply(&client), 70);
+}
+
+#[test]
+fn test_charity_sink() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+    let charity = Address::generate(&env);
+
+    client.set_charity_address(&admin, &charity);
+    client.set_rounding_sink(&admin, &RoundingSink::Charity);
+    verify_paper(&env, &client, &submitter, &verifier);
+
+    assert_eq!(tokens_earned(&client, &submitter), 69);
+    assert_eq!(client.get_charity_accrued(), 1);
+    assert_eq!(tokens_earned(&client, &submitter) + client.get_charity_accrued() as u128, 70);
+    assert_eq!(rewards_to(&env, &charity), std::vec![1]);
+}
+
+#[test]
+fn test_charity_sink_without_charity_address_still_accrues() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_rounding_sink(&admin, &RoundingSink::Charity);
+    verify_paper(&env, &client, &submitter, &verifier);
+
+    assert_eq!(tokens_earned(&client, &submitter), 69);
+    assert_eq!(client.get_charity_accrued(), 1);
+}
+
+#[test]
+fn test_treasury_sink() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_rounding_sink(&admin, &RoundingSink::Treasury);
+    verify_paper(&env, &client, &submitter, &verifier);
+
+    assert_eq!(tokens_earned(&client, &submitter), 69);
+    assert_eq!(client.get_treasury_balance(), 1);
+    assert_eq!(tokens_earned(&client, &submitter) + client.get_treasury_balance(), 70);
+}
+
+#[test]
+fn test_burn_sink_reduces_supply() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_rounding_sink(&admin, &RoundingSink::Burn);
+    verify_paper(&env, &client, &submitter, &verifier);
+
+    assert_eq!(tokens_earned(&client, &submitter), 69);
+    assert_eq!(client.get_total_burned(), 1);
+    assert_eq!(supply(&client), 69);
+    assert_eq!(client.get_treasury_balance(), 0);
+}
+
+#[test]
+fn test_explicit_submitter_percentage_defaults_to_charity() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup(&env);
+
+    client.set_submitter_percentage(&admin, &50);
+    assert_eq!(client.get_rounding_sink(), RoundingSink::Charity);
+
+    client.set_rounding_sink(&admin, &RoundingSink::Treasury);
+    assert_eq!(client.get_rounding_sink(), RoundingSink::Treasury);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_rounding_sink_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_rounding_sink(&submitter, &RoundingSink::Burn);
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, Symbol, TryIntoVal, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let charity = Address::generate(env);
+
+    let admin = common::init_admin(env, &client);
+    client.set_charity_contract(&admin, &charity);
+
+    (client, admin, charity)
+}
+
+#[test]
+fn test_set_charity_address_routes_donations_to_new_charity() {
+    let env = Env::default();
+    let (client, admin, old_charity) = setup(&env);
+    let new_charity = Address::generate(&env);
+
+    client.set_charity_address(&admin, &new_charity);
+    assert_eq!(client.get_charity_contract(), Some(new_charity.clone()));
+
+    client.set_token_address(&admin, &Address::generate(&env));
+    let donor = common::register(&env, &client, ParticipantRole::Recycler, symbol_short!("donor"));
+    client.reward_tokens(&Address::generate(&env), &donor, &500, &1);
+
+    client.donate_to_charity(&donor, &100);
+
+    let event = env.events().all().last().unwrap();
+    let event_data: (i128, Address) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data.0, 100);
+    assert_eq!(event_data.1, new_charity);
+    assert_ne!(event_data.1, old_charity);
+}
+
+#[test]
+fn test_set_charity_address_emits_event() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+    let new_charity = Address::generate(&env);
+
+    client.set_charity_address(&admin, &new_charity);
+
+    let event = env.events().all().last().unwrap();
+    let expected_topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("config"), Symbol::new(&env, "charity_changed")).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    let event_data: Address = event.2.try_into_val(&env).unwrap();
+    assert_eq!(event_data, new_charity);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_charity_address_by_non_admin_fails() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    client.set_charity_address(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Admin not initialized")]
+fn test_set_charity_address_not_initialized_fails() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    client.set_charity_address(&Address::generate(&env), &Address::generate(&env));
+}
@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+
+    (client, admin, collector)
+}
+
+#[test]
+fn test_split_waste_success() {
+    let env = Env::default();
+    let (client, _, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &3000, &collector, &1_000_000, &2_000_000);
+    let pieces = client.split_waste(&waste_id, &collector, &vec![&env, 1000u128, 2000u128]);
+
+    assert_eq!(pieces.len(), 2);
+    assert!(!client.get_waste_v2(&waste_id).unwrap().is_active);
+
+    let first = client.get_waste_v2(&pieces.get(0).unwrap()).unwrap();
+    let second = client.get_waste_v2(&pieces.get(1).unwrap()).unwrap();
+    assert_eq!(first.weight, 1000);
+    assert_eq!(second.weight, 2000);
+    assert_eq!(first.waste_type, WasteType::Metal);
+    assert_eq!(first.current_owner, collector);
+    assert_eq!(first.latitude, 1_000_000);
+
+    let owned = client.get_participant_wastes_v2(&collector);
+    assert!(owned.contains(pieces.get(0).unwrap()));
+    assert!(owned.contains(pieces.get(1).unwrap()));
+}
+
+#[test]
+#[should_panic(expected = "Invalid split weights")]
+fn test_split_waste_empty_weights() {
+    let env = Env::default();
+    let (client, _, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &3000, &collector, &0, &0);
+    client.split_waste(&waste_id, &collector, &Vec::new(&env));
+}
+
+#[test]
+#[should_panic(expected = "Invalid split weights")]
+fn test_split_waste_zero_weight_element() {
+    let env = Env::default();
+    let (client, _, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &3000, &collector, &0, &0);
+    client.split_waste(&waste_id, &collector, &vec![&env, 3000u128, 0u128]);
+}
+
+#[test]
+#[should_panic(expected = "Too many splits")]
+fn test_split_waste_over_limit() {
+    let env = Env::default();
+    let (client, admin, collector) = setup(&env);
+
+    client.set_max_splits(&admin, &2);
+    assert_eq!(client.get_config().max_splits, 2);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &3000, &collector, &0, &0);
+    client.split_waste(&waste_id, &collector, &vec![&env, 1000u128, 1000u128, 1000u128]);
+}
+
+#[test]
+#[should_panic(expected = "Split weights must sum to waste weight")]
+fn test_split_waste_weight_mismatch() {
+    let env = Env::default();
+    let (client, _, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &3000, &collector, &0, &0);
+    client.split_waste(&waste_id, &collector, &vec![&env, 1000u128, 1000u128]);
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("Rec"));
+
+    (client, recycler)
+}
+
+#[test]
+fn test_submit_material_with_location_round_trips() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    let material = client.submit_material_with_location(
+        &WasteType::Glass,
+        &2000,
+        &recycler,
+        &String::from_str(&env, "Bottles"),
+        &40_712_800,
+        &-74_006_000,
+    );
+    assert_eq!(material.latitude, 40_712_800);
+    assert_eq!(material.longitude, -74_006_000);
+
+    let stored = client.get_material(&material.id).unwrap();
+    assert_eq!(stored.latitude, 40_712_800);
+    assert_eq!(stored.longitude, -74_006_000);
+    assert_eq!(stored.weight, 2000);
+    assert_eq!(stored.submitter, recycler);
+}
+
+#[test]
+fn test_submit_material_defaults_location_to_zero() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    let material = client.submit_material(
+        &WasteType::Paper,
+        &1000,
+        &recycler,
+        &String::from_str(&env, "Newspaper"),
+    );
+
+    let stored = client.get_material(&material.id).unwrap();
+    assert_eq!(stored.latitude, 0);
+    assert_eq!(stored.longitude, 0);
+}
+
+#[test]
+fn test_submit_material_with_location_updates_stats() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    client.submit_material_with_location(
+        &WasteType::Metal,
+        &3000,
+        &recycler,
+        &String::from_str(&env, "Cans"),
+        &1_000_000,
+        &2_000_000,
+    );
+
+    let stats = client.get_stats(&recycler).unwrap();
+    assert_eq!(stats.total_submissions, 1);
+    assert_eq!(stats.total_weight, 3000);
+}
+
+#[test]
+#[should_panic(expected = "Latitude must be between -90 and +90 degrees")]
+fn test_submit_material_with_invalid_location() {
+    let env = Env::default();
+    let (client, recycler) = setup(&env);
+
+    client.submit_material_with_location(
+        &WasteType::Metal,
+        &3000,
+        &recycler,
+        &String::from_str(&env, "Cans"),
+        &91_000_000,
+        &0,
+    );
+}
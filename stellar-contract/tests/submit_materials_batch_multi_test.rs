@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let alice = common::register(env, &client, ParticipantRole::Collector, symbol_short!("alice"));
+    let bob = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("bob"));
+
+    (client, alice, bob)
+}
+
+#[test]
+fn test_two_submitters_get_independent_stats() {
+    let env = Env::default();
+    let (client, alice, bob) = setup(&env);
+    let desc = String::from_str(&env, "Mixed");
+
+    let entries = vec![
+        &env,
+        (alice.clone(), WasteType::Paper, 1000u64, desc.clone()),
+        (bob.clone(), WasteType::Metal, 3000u64, desc.clone()),
+        (alice.clone(), WasteType::Glass, 2000u64, desc.clone()),
+    ];
+    let materials = client.submit_materials_batch_multi(&entries);
+
+    assert_eq!(materials.len(), 3);
+    assert_eq!(materials.get(0).unwrap().submitter, alice);
+    assert_eq!(materials.get(1).unwrap().submitter, bob);
+    assert_eq!(materials.get(2).unwrap().submitter, alice);
+
+    let alice_stats = client.get_stats(&alice).unwrap();
+    assert_eq!(alice_stats.total_submissions, 2);
+    assert_eq!(alice_stats.total_weight, 3000);
+    assert_eq!(alice_stats.paper_count, 1);
+    assert_eq!(alice_stats.glass_count, 1);
+    assert_eq!(alice_stats.metal_count, 0);
+
+    let bob_stats = client.get_stats(&bob).unwrap();
+    assert_eq!(bob_stats.total_submissions, 1);
+    assert_eq!(bob_stats.total_weight, 3000);
+    assert_eq!(bob_stats.metal_count, 1);
+
+    assert_eq!(client.get_participant(&alice).unwrap().total_waste_processed, 3000);
+    assert_eq!(client.get_participant(&bob).unwrap().total_waste_processed, 3000);
+    assert_eq!(client.get_submission_history(&alice).len(), 2);
+    assert_eq!(client.get_submission_history(&bob).len(), 1);
+}
+
+#[test]
+fn test_accumulates_onto_existing_stats() {
+    let env = Env::default();
+    let (client, alice, bob) = setup(&env);
+    let desc = String::from_str(&env, "Mixed");
+
+    client.submit_material(&WasteType::Paper, &500, &alice, &desc);
+    client.submit_materials_batch_multi(&vec![
+        &env,
+        (alice.clone(), WasteType::Paper, 1500u64, desc.clone()),
+        (bob.clone(), WasteType::Paper, 700u64, desc.clone()),
+    ]);
+
+    let alice_stats = client.get_stats(&alice).unwrap();
+    assert_eq!(alice_stats.total_submissions, 2);
+    assert_eq!(alice_stats.total_weight, 2000);
+    assert_eq!(client.get_stats(&bob).unwrap().total_weight, 700);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not a registered participant")]
+fn test_unregistered_submitter_rejected() {
+    let env = Env::default();
+    let (client, alice, _) = setup(&env);
+    let desc = String::from_str(&env, "Mixed");
+
+    client.submit_materials_batch_multi(&vec![
+        &env,
+        (alice, WasteType::Paper, 1000u64, desc.clone()),
+        (Address::generate(&env), WasteType::Paper, 1000u64, desc),
+    ]);
+}
@@ -0,0 +1,146 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, String, TryIntoVal, Val, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+/// Collect the amounts of all reward events emitted to `recipient`
+fn rewards_to(env: &Env, recipient: &Address) -> std::vec::Vec<u128> {
+    let expected_topics: Vec<Val> = (symbol_short!("rewarded"), recipient.clone()).into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == expected_topics)
+        .map(|(_, _, data)| {
+            let (amount, _, _): (u128, u64, u64) = data.try_into_val(env).unwrap();
+            amount
+        })
+        .collect()
+}
+
+#[test]
+fn test_submitter_receives_exact_percentage() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+    let charity = Address::generate(&env);
+
+    client.set_charity_address(&admin, &charity);
+    client.set_percentages(&admin, &10, &30);
+    client.set_submitter_percentage(&admin, &55);
+    assert_eq!(client.get_submitter_percentage(), Some(55));
+    assert_eq!(client.get_config().submitter_percentage, Some(55));
+
+    // Metal 3kg = 150 points
+    let material = client.submit_material(&WasteType::Metal, &3000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &verifier);
+
+    // Owner share (30%) then explicit submitter share (55%)
+    assert_eq!(rewards_to(&env, &submitter), std::vec![45, 82]);
+    // Rounding leftover goes to charity
+    assert_eq!(rewards_to(&env, &charity), std::vec![23]);
+}
+
+#[test]
+fn test_submitter_receives_remainder_when_unset() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_percentages(&admin, &10, &30);
+    assert_eq!(client.get_submitter_percentage(), None);
+
+    let material = client.submit_material(&WasteType::Metal, &3000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(rewards_to(&env, &submitter), std::vec![45, 105]);
+}
+
+#[test]
+#[should_panic(expected = "Total percentages cannot exceed 100")]
+fn test_submitter_percentage_total_over_100() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup(&env);
+
+    client.set_percentages(&admin, &10, &30);
+    client.set_submitter_percentage(&admin, &61);
+}
+
+#[test]
+#[should_panic(expected = "Total percentages cannot exceed 100")]
+fn test_owner_percentage_respects_submitter_share() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup(&env);
+
+    client.set_percentages(&admin, &10, &30);
+    client.set_submitter_percentage(&admin, &50);
+    client.set_owner_percentage(&admin, &41);
+}
+
+#[test]
+#[should_panic(expected = "Total percentages cannot exceed 100")]
+fn test_owner_percentage_respects_default_collector_share() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup(&env);
+
+    // Unconfigured contract: the collector share defaults to 5%
+    client.set_owner_percentage(&admin, &96);
+}
+
+#[test]
+#[should_panic(expected = "Total percentages cannot exceed 100")]
+fn test_collector_percentage_respects_default_owner_share() {
+    let env = Env::default();
+    let (client, admin, _, _) = setup(&env);
+
+    // Unconfigured contract: the owner share defaults to 50%
+    client.set_collector_percentage(&admin, &51);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_submitter_percentage_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_submitter_percentage(&submitter, &50);
+}
+
+#[test]
+fn test_multi_hop_payout_never_exceeds_reward() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+    let first_hop = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("hop1"));
+    let second_hop = common::register(&env, &client, ParticipantRole::Collector, symbol_short!("hop2"));
+
+    client.set_percentages(&admin, &10, &40);
+    client.set_submitter_percentage(&admin, &50);
+
+    // Metal 2kg = 100 points, passed through two collectors
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    client.transfer_waste(&material.id, &submitter, &first_hop, &String::from_str(&env, "hop"));
+    client.transfer_waste(&material.id, &first_hop, &second_hop, &String::from_str(&env, "hop"));
+    client.verify_material(&material.id, &verifier);
+
+    let total_paid: u128 = [&submitter, &first_hop, &second_hop, &verifier]
+        .iter()
+        .flat_map(|address| rewards_to(&env, address))
+        .sum();
+    assert!(total_paid <= 100);
+    // Two collector hops (10 + 10), owner share (40), submitter capped at the remaining 40
+    assert_eq!(rewards_to(&env, &second_hop), std::vec![10, 40, 40]);
+    assert_eq!(total_paid, 100);
+}
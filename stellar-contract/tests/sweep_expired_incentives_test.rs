@@ -0,0 +1,67 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, admin, manufacturer)
+}
+
+#[test]
+fn test_expired_incentives_deactivated() {
+    let env = Env::default();
+    let (client, admin, manufacturer) = setup(&env);
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Plastic, &10, &1000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Metal, &10, &1000);
+    let open_ended = client.create_incentive(&manufacturer, &WasteType::Glass, &10, &1000);
+    let later = client.create_incentive(&manufacturer, &WasteType::Paper, &10, &1000);
+
+    client.set_incentive_expiry(&first.id, &100);
+    client.set_incentive_expiry(&second.id, &200);
+    client.set_incentive_expiry(&later.id, &10_000);
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(client.sweep_expired_incentives(&admin, &10), 2);
+
+    assert!(!client.get_incentive_by_id(&first.id).unwrap().active);
+    assert!(!client.get_incentive_by_id(&second.id).unwrap().active);
+    assert!(client.get_incentive_by_id(&open_ended.id).unwrap().active);
+    assert!(client.get_incentive_by_id(&later.id).unwrap().active);
+
+    // Already swept incentives aren't counted again
+    assert_eq!(client.sweep_expired_incentives(&admin, &10), 0);
+}
+
+#[test]
+fn test_limit_caps_sweep() {
+    let env = Env::default();
+    let (client, admin, manufacturer) = setup(&env);
+
+    let first = client.create_incentive(&manufacturer, &WasteType::Plastic, &10, &1000);
+    let second = client.create_incentive(&manufacturer, &WasteType::Metal, &10, &1000);
+    client.set_incentive_expiry(&first.id, &100);
+    client.set_incentive_expiry(&second.id, &100);
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(client.sweep_expired_incentives(&admin, &1), 1);
+
+    assert!(!client.get_incentive_by_id(&first.id).unwrap().active);
+    assert!(client.get_incentive_by_id(&second.id).unwrap().active);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_sweep_non_admin() {
+    let env = Env::default();
+    let (client, _, manufacturer) = setup(&env);
+
+    client.sweep_expired_incentives(&manufacturer, &10);
+}
@@ -0,0 +1,57 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, admin, recycler)
+}
+
+#[test]
+fn test_count_tracks_creation_and_deactivation() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    assert_eq!(client.get_total_active_wastes(), 0);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+    client.recycle_waste(&WasteType::Glass, &1000, &recycler, &0, &0);
+    assert_eq!(client.get_total_active_wastes(), 3);
+
+    client.deactivate_waste(&first, &admin);
+    assert_eq!(client.get_total_active_wastes(), 2);
+}
+
+#[test]
+fn test_split_replaces_parent_with_pieces() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.split_waste(&waste_id, &recycler, &vec![&env, 400u128, 600u128]);
+
+    assert_eq!(client.get_total_active_wastes(), 2);
+}
+
+#[test]
+fn test_expired_wastes_leave_active_count() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    client.set_waste_expiry(&admin, &100);
+    client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.expire_stale_wastes(&admin, &10);
+
+    assert_eq!(client.get_total_active_wastes(), 0);
+}
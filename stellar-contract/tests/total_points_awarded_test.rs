@@ -0,0 +1,51 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, verifier)
+}
+
+fn register(env: &Env, client: &ScavengerContractClient) -> Address {
+    common::register(env, client, ParticipantRole::Collector, symbol_short!("col"))
+}
+
+#[test]
+fn test_total_points_awarded_starts_at_zero() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(client.get_total_points_awarded(), 0);
+}
+
+#[test]
+fn test_total_points_awarded_matches_sum_of_participants() {
+    let env = Env::default();
+    let (client, verifier) = setup(&env);
+    let alice = register(&env, &client);
+    let bob = register(&env, &client);
+
+    let desc = String::from_str(&env, "Batch");
+    let m1 = client.submit_material(&WasteType::Metal, &2000, &alice, &desc);
+    let m2 = client.submit_material(&WasteType::Paper, &3000, &alice, &desc);
+    let m3 = client.submit_material(&WasteType::PetPlastic, &1000, &bob, &desc);
+    // Unverified material earns nothing
+    client.submit_material(&WasteType::Glass, &5000, &bob, &desc);
+
+    client.verify_material(&m1.id, &verifier);
+    client.verify_materials_batch(&vec![&env, m2.id, m3.id], &verifier);
+
+    let alice_points = client.get_stats(&alice).unwrap().total_points;
+    let bob_points = client.get_stats(&bob).unwrap().total_points;
+
+    assert_eq!(alice_points, 100 + 30);
+    assert_eq!(bob_points, 30);
+    assert_eq!(client.get_total_points_awarded(), alice_points + bob_points);
+}
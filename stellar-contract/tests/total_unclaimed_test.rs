@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let alice = common::register(env, &client, ParticipantRole::Collector, symbol_short!("alice"));
+    let bob = common::register(env, &client, ParticipantRole::Collector, symbol_short!("bob"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, alice, bob, verifier)
+}
+
+fn submit_and_verify(
+    env: &Env,
+    client: &ScavengerContractClient,
+    submitter: &Address,
+    verifier: &Address,
+    weight: u64,
+) {
+    let material = client.submit_material(&WasteType::Metal, &weight, submitter, &String::from_str(env, "Cans"));
+    client.verify_material(&material.id, verifier);
+}
+
+#[test]
+fn test_total_unclaimed_aggregates_participants() {
+    let env = Env::default();
+    let (client, alice, bob, verifier) = setup(&env);
+
+    assert_eq!(client.get_total_unclaimed(), 0);
+
+    // Metal: 2kg = 100 points, 4kg = 200 points
+    submit_and_verify(&env, &client, &alice, &verifier, 2000);
+    submit_and_verify(&env, &client, &bob, &verifier, 4000);
+
+    assert_eq!(client.get_unclaimed_rewards(&alice), 100);
+    assert_eq!(client.get_unclaimed_rewards(&bob), 200);
+    assert_eq!(client.get_total_unclaimed(), 300);
+}
+
+#[test]
+fn test_claim_decreases_total_unclaimed() {
+    let env = Env::default();
+    let (client, alice, bob, verifier) = setup(&env);
+
+    submit_and_verify(&env, &client, &alice, &verifier, 2000);
+    submit_and_verify(&env, &client, &bob, &verifier, 4000);
+
+    let claimed = client.claim_rewards(&alice);
+    assert_eq!(claimed, 100);
+
+    assert_eq!(client.get_unclaimed_rewards(&alice), 0);
+    assert_eq!(client.get_total_unclaimed(), 200);
+
+    // Earned totals are unaffected by claiming
+    assert_eq!(client.get_participant(&alice).unwrap().total_tokens_earned, 100);
+}
+
+#[test]
+#[should_panic(expected = "No rewards to claim")]
+fn test_claim_without_rewards() {
+    let env = Env::default();
+    let (client, alice, _, _) = setup(&env);
+
+    client.claim_rewards(&alice);
+}
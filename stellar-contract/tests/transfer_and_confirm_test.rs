@@ -0,0 +1,45 @@
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("Rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("Col"));
+
+    (client, recycler, collector)
+}
+
+#[test]
+fn test_transfer_and_confirm_sets_recipient_as_confirmer() {
+    let env = Env::default();
+    let (client, recycler, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &2000, &recycler, &0, &0);
+
+    let waste = client.transfer_and_confirm(&waste_id, &recycler, &collector, &1_000_000, &2_000_000);
+    assert_eq!(waste.current_owner, collector);
+    assert!(waste.is_confirmed);
+    assert_eq!(waste.confirmer, collector);
+
+    let stored = client.get_waste_v2(&waste_id).unwrap();
+    assert!(stored.is_confirmed);
+    assert_eq!(stored.confirmer, collector);
+
+    let history = client.get_waste_transfer_history_v2(&waste_id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().to, collector);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the owner of this waste item")]
+fn test_transfer_and_confirm_requires_owner() {
+    let env = Env::default();
+    let (client, recycler, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &2000, &recycler, &0, &0);
+
+    client.transfer_and_confirm(&waste_id, &collector, &recycler, &0, &0);
+}
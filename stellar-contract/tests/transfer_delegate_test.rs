@@ -0,0 +1,68 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let agent = Address::generate(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+
+    (client, recycler, collector, agent)
+}
+
+#[test]
+fn test_delegate_transfers_owner_waste() {
+    let env = Env::default();
+    let (client, recycler, collector, agent) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.add_transfer_delegate(&recycler, &agent);
+    assert!(client.is_transfer_delegate(&recycler, &agent));
+
+    let transfer = client.transfer_waste_v2(&waste_id, &agent, &collector, &0, &0);
+
+    // Recorded as a transfer from the owner, not the agent
+    assert_eq!(transfer.from, recycler);
+    assert_eq!(transfer.to, collector);
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().current_owner, collector);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the owner of this waste item")]
+fn test_non_delegate_cannot_transfer() {
+    let env = Env::default();
+    let (client, recycler, collector, agent) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+
+    client.transfer_waste_v2(&waste_id, &agent, &collector, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the owner of this waste item")]
+fn test_removed_delegate_cannot_transfer() {
+    let env = Env::default();
+    let (client, recycler, collector, agent) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.add_transfer_delegate(&recycler, &agent);
+    client.remove_transfer_delegate(&recycler, &agent);
+    assert!(!client.is_transfer_delegate(&recycler, &agent));
+
+    client.transfer_waste_v2(&waste_id, &agent, &collector, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Delegate not found")]
+fn test_remove_unknown_delegate_panics() {
+    let env = Env::default();
+    let (client, recycler, _, agent) = setup(&env);
+
+    client.remove_transfer_delegate(&recycler, &agent);
+}
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    // Give the collector 100 pending reward points
+    let material = client.submit_material(&WasteType::Metal, &2000, &collector, &String::from_str(env, "Cans"));
+    client.verify_material(&material.id, &recycler);
+
+    (client, admin, collector, manufacturer)
+}
+
+#[test]
+fn test_charity_accrues_fee_per_transfer() {
+    let env = Env::default();
+    let (client, admin, collector, manufacturer) = setup(&env);
+
+    client.set_transfer_fee_points(&admin, &3);
+    assert_eq!(client.get_transfer_fee_points(), 3);
+    assert_eq!(client.get_config().transfer_fee_points, 3);
+    assert_eq!(client.get_unclaimed_rewards(&collector), 100);
+
+    let w1 = client.recycle_waste(&WasteType::Paper, &1000, &collector, &0, &0);
+    let w2 = client.recycle_waste(&WasteType::Paper, &1000, &collector, &0, &0);
+    client.transfer_waste_v2(&w1, &collector, &manufacturer, &0, &0);
+    assert_eq!(client.get_charity_accrued(), 3);
+    client.transfer_waste_v2(&w2, &collector, &manufacturer, &0, &0);
+    assert_eq!(client.get_charity_accrued(), 6);
+
+    assert_eq!(client.get_unclaimed_rewards(&collector), 94);
+    assert_eq!(client.get_total_unclaimed(), 94);
+}
+
+#[test]
+fn test_zero_fee_transfer_unaffected() {
+    let env = Env::default();
+    let (client, _, collector, manufacturer) = setup(&env);
+
+    assert_eq!(client.get_transfer_fee_points(), 0);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &1000, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+
+    assert_eq!(client.get_charity_accrued(), 0);
+    assert_eq!(client.get_unclaimed_rewards(&collector), 100);
+}
+
+#[test]
+fn test_fee_capped_at_unclaimed_balance() {
+    let env = Env::default();
+    let (client, admin, collector, manufacturer) = setup(&env);
+
+    client.set_transfer_fee_points(&admin, &150);
+
+    let waste_id = client.recycle_waste(&WasteType::Paper, &1000, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+
+    assert_eq!(client.get_charity_accrued(), 100);
+    assert_eq!(client.get_unclaimed_rewards(&collector), 0);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_transfer_fee_non_admin() {
+    let env = Env::default();
+    let (client, _, collector, _) = setup(&env);
+
+    client.set_transfer_fee_points(&collector, &3);
+}
@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+/// Registers a waste and moves it recycler -> collector -> manufacturer,
+/// returning the waste ID and the expected (from, to) pairs in order
+fn setup_history(env: &Env) -> (ScavengerContractClient<'_>, u128, [(Address, Address); 2]) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+
+    (
+        client,
+        waste_id,
+        [(recycler, collector.clone()), (collector, manufacturer)],
+    )
+}
+
+#[test]
+fn test_paged_history_returns_slices_in_order() {
+    let env = Env::default();
+    let (client, waste_id, expected) = setup_history(&env);
+    let id = waste_id as u64;
+
+    let first = client.get_transfer_history_paged(&id, &0, &1);
+    assert_eq!(first.len(), 1);
+    assert_eq!(first.get(0).unwrap().from, expected[0].0);
+    assert_eq!(first.get(0).unwrap().to, expected[0].1);
+
+    let second = client.get_transfer_history_paged(&id, &1, &1);
+    assert_eq!(second.len(), 1);
+    assert_eq!(second.get(0).unwrap().from, expected[1].0);
+    assert_eq!(second.get(0).unwrap().to, expected[1].1);
+}
+
+#[test]
+fn test_paged_history_limit_clamped_to_end() {
+    let env = Env::default();
+    let (client, waste_id, _) = setup_history(&env);
+
+    let page = client.get_transfer_history_paged(&(waste_id as u64), &1, &10);
+    assert_eq!(page.len(), 1);
+
+    let all = client.get_transfer_history_paged(&(waste_id as u64), &0, &10);
+    assert_eq!(all, client.get_transfer_history(&(waste_id as u64)));
+}
+
+#[test]
+fn test_paged_history_empty_past_end() {
+    let env = Env::default();
+    let (client, waste_id, _) = setup_history(&env);
+
+    assert_eq!(client.get_transfer_history_paged(&(waste_id as u64), &2, &5).len(), 0);
+    assert_eq!(client.get_transfer_history_paged(&999, &0, &5).len(), 0);
+}
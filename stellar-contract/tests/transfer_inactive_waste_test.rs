@@ -0,0 +1,60 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, Vec};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, admin, collector, manufacturer)
+}
+
+#[test]
+fn test_active_waste_transfers() {
+    let env = Env::default();
+    let (client, _, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1000, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+
+    assert_eq!(client.get_waste_v2(&waste_id).unwrap().current_owner, manufacturer);
+}
+
+#[test]
+#[should_panic(expected = "Cannot transfer inactive waste")]
+fn test_inactive_waste_transfer_panics() {
+    let env = Env::default();
+    let (client, admin, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1000, &collector, &0, &0);
+    client.deactivate_waste(&waste_id, &admin);
+
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+}
+
+#[test]
+fn test_batch_transfer_skips_inactive_wastes() {
+    let env = Env::default();
+    let (client, admin, collector, manufacturer) = setup(&env);
+
+    let active_id = client.recycle_waste(&WasteType::Metal, &1000, &collector, &0, &0);
+    let inactive_id = client.recycle_waste(&WasteType::Glass, &2000, &collector, &0, &0);
+    client.deactivate_waste(&inactive_id, &admin);
+
+    let mut ids = Vec::new(&env);
+    ids.push_back(active_id);
+    ids.push_back(inactive_id);
+
+    let transfers = client.transfer_wastes_batch(&ids, &collector, &manufacturer, &0, &0);
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers.get(0).unwrap().waste_id, active_id);
+
+    assert_eq!(client.get_waste_v2(&active_id).unwrap().current_owner, manufacturer);
+    assert_eq!(client.get_waste_v2(&inactive_id).unwrap().current_owner, collector);
+}
@@ -0,0 +1,38 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+
+    (client, submitter)
+}
+
+#[test]
+fn test_weights_accumulate_per_type() {
+    let env = Env::default();
+    let (client, submitter) = setup(&env);
+
+    client.submit_material(&WasteType::Paper, &1000, &submitter, &String::from_str(&env, "a"));
+    client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "b"));
+    client.submit_material(&WasteType::Metal, &500, &submitter, &String::from_str(&env, "c"));
+
+    let stats = client.get_stats(&submitter).unwrap();
+    assert_eq!(stats.paper_weight, 3000);
+    assert_eq!(stats.metal_weight, 500);
+
+    assert_eq!(client.get_type_weights(&submitter), (3000, 0, 0, 500, 0));
+}
+
+#[test]
+fn test_no_submissions_returns_zeros() {
+    let env = Env::default();
+    let (client, submitter) = setup(&env);
+
+    assert_eq!(client.get_type_weights(&submitter), (0, 0, 0, 0, 0));
+}
@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Env, IntoVal, TryIntoVal, Val, Vec,
+};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address) {
+    let client = common::setup_contract(env);
+
+    let owner = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, owner)
+}
+
+#[test]
+fn test_location_updated_without_transfer() {
+    let env = Env::default();
+    let (client, owner) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &10_000_000, &20_000_000);
+    assert_eq!(client.get_waste_location(&waste_id), Some((10_000_000, 20_000_000)));
+
+    let waste = client.update_waste_location(&waste_id, &owner, &-33_868_800, &151_209_300);
+    assert_eq!(waste.current_owner, owner);
+    assert_eq!(client.get_waste_location(&waste_id), Some((-33_868_800, 151_209_300)));
+    assert_eq!(client.get_waste_transfer_history_v2(&waste_id).len(), 0);
+}
+
+#[test]
+fn test_relocation_emits_event() {
+    let env = Env::default();
+    let (client, owner) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    client.update_waste_location(&waste_id, &owner, &1_000_000, &2_000_000);
+
+    let event = env.events().all().last().unwrap();
+    let expected_topics: Vec<Val> = (symbol_short!("waste"), symbol_short!("relocated")).into_val(&env);
+    assert_eq!(event.1, expected_topics);
+
+    let data: (u128, i128, i128) = event.2.try_into_val(&env).unwrap();
+    assert_eq!(data, (waste_id, 1_000_000, 2_000_000));
+}
+
+#[test]
+fn test_missing_waste_has_no_location() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(client.get_waste_location(&999), None);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the owner of this waste item")]
+fn test_non_owner_cannot_relocate() {
+    let env = Env::default();
+    let (client, owner) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    client.update_waste_location(&waste_id, &Address::generate(&env), &1, &1);
+}
+
+#[test]
+#[should_panic(expected = "Latitude must be between -90 and +90 degrees")]
+fn test_invalid_coordinates_rejected() {
+    let env = Env::default();
+    let (client, owner) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &owner, &0, &0);
+    client.update_waste_location(&waste_id, &owner, &91_000_000, &0);
+}
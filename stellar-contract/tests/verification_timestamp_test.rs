@@ -0,0 +1,38 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Ledger, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, submitter, verifier)
+}
+
+#[test]
+fn test_timestamp_recorded_on_verification() {
+    let env = Env::default();
+    let (client, submitter, verifier) = setup(&env);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let material = client.submit_material(&WasteType::Paper, &2000, &submitter, &String::from_str(&env, "Paper"));
+    assert_eq!(client.get_verification_timestamp(&material.id), Some(0));
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(client.get_verification_timestamp(&material.id), Some(5_000));
+}
+
+#[test]
+fn test_missing_material_returns_none() {
+    let env = Env::default();
+    let (client, _, _) = setup(&env);
+
+    assert_eq!(client.get_verification_timestamp(&999), None);
+}
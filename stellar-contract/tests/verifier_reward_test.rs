@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+#[test]
+fn test_verifier_credited_with_configured_reward() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_verifier_reward_points(&admin, &15);
+    assert_eq!(client.get_verifier_reward_points(), 15);
+    assert_eq!(client.get_config().verifier_reward_points, 15);
+
+    let before = client.get_participant(&verifier).unwrap().total_tokens_earned;
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &verifier);
+
+    let after = client.get_participant(&verifier).unwrap().total_tokens_earned;
+    assert_eq!(after - before, 15);
+
+    // Submitter reward is unaffected
+    assert_eq!(client.get_participant(&submitter).unwrap().total_tokens_earned, 100);
+}
+
+#[test]
+fn test_verifier_reward_defaults_to_zero() {
+    let env = Env::default();
+    let (client, _, submitter, verifier) = setup(&env);
+
+    assert_eq!(client.get_verifier_reward_points(), 0);
+
+    let material = client.submit_material(&WasteType::Metal, &2000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_material(&material.id, &verifier);
+
+    assert_eq!(client.get_participant(&verifier).unwrap().total_tokens_earned, 0);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_verifier_reward_non_admin() {
+    let env = Env::default();
+    let (client, _, _, verifier) = setup(&env);
+
+    client.set_verifier_reward_points(&verifier, &15);
+}
+
+#[test]
+fn test_verifier_credited_per_batch_verified_material() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    client.set_verifier_reward_points(&admin, &15);
+
+    let desc = String::from_str(&env, "Cans");
+    let first = client.submit_material(&WasteType::Metal, &2000, &submitter, &desc);
+    let second = client.submit_material(&WasteType::Metal, &2000, &submitter, &desc);
+
+    client.verify_materials_batch(&soroban_sdk::vec![&env, first.id, second.id], &verifier);
+    assert_eq!(client.get_participant(&verifier).unwrap().total_tokens_earned, 30);
+}
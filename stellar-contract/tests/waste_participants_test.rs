@@ -0,0 +1,47 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_participants_across_transfer_chain() {
+    let env = Env::default();
+    let (client, recycler, collector, manufacturer) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.transfer_waste_v2(&waste_id, &recycler, &collector, &0, &0);
+    client.transfer_waste_v2(&waste_id, &collector, &manufacturer, &0, &0);
+
+    let participants = client.get_waste_participants(&(waste_id as u64));
+    assert_eq!(participants, vec![&env, recycler, collector, manufacturer]);
+}
+
+#[test]
+fn test_untransferred_waste_returns_owner_only() {
+    let env = Env::default();
+    let (client, recycler, _, _) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+
+    assert_eq!(client.get_waste_participants(&(waste_id as u64)), vec![&env, recycler]);
+}
+
+#[test]
+fn test_missing_waste_returns_empty() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert!(client.get_waste_participants(&999).is_empty());
+}
@@ -0,0 +1,57 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+
+    (client, admin, recycler, collector)
+}
+
+#[test]
+fn test_active_unconfirmed_waste() {
+    let env = Env::default();
+    let (client, _, recycler, _) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+
+    assert_eq!(client.get_waste_status(&waste_id), Some(String::from_str(&env, "active")));
+}
+
+#[test]
+fn test_active_confirmed_waste() {
+    let env = Env::default();
+    let (client, _, recycler, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.confirm_waste_details(&waste_id, &collector);
+
+    assert_eq!(client.get_waste_status(&waste_id), Some(String::from_str(&env, "confirmed")));
+}
+
+#[test]
+fn test_deactivated_waste() {
+    let env = Env::default();
+    let (client, admin, recycler, collector) = setup(&env);
+
+    let waste_id = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    client.confirm_waste_details(&waste_id, &collector);
+    client.deactivate_waste(&waste_id, &admin);
+
+    assert_eq!(client.get_waste_status(&waste_id), Some(String::from_str(&env, "inactive")));
+}
+
+#[test]
+fn test_missing_waste_returns_none() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert_eq!(client.get_waste_status(&999), None);
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("Rec"));
+
+    (client, admin, recycler)
+}
+
+#[test]
+fn test_allowlisted_type_accepted() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    client.add_allowed_type(&admin, &WasteType::Metal);
+    client.add_allowed_type(&admin, &WasteType::Glass);
+    assert_eq!(client.get_allowed_types(), vec![&env, WasteType::Metal, WasteType::Glass]);
+
+    let material = client.submit_material(&WasteType::Metal, &1000, &recycler, &String::from_str(&env, "Cans"));
+    assert_eq!(material.waste_type, WasteType::Metal);
+
+    let waste_id = client.recycle_waste(&WasteType::Glass, &1000, &recycler, &0, &0);
+    assert!(client.get_waste_v2(&waste_id).is_some());
+}
+
+#[test]
+#[should_panic(expected = "Waste type not accepted")]
+fn test_submit_material_disallowed_type_panics() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    client.add_allowed_type(&admin, &WasteType::Metal);
+    client.add_allowed_type(&admin, &WasteType::Glass);
+
+    client.submit_material(&WasteType::Paper, &1000, &recycler, &String::from_str(&env, "Paper"));
+}
+
+#[test]
+#[should_panic(expected = "Waste type not accepted")]
+fn test_recycle_waste_disallowed_type_panics() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    client.add_allowed_type(&admin, &WasteType::Metal);
+
+    client.recycle_waste(&WasteType::Paper, &1000, &recycler, &0, &0);
+}
+
+#[test]
+fn test_empty_allowlist_accepts_all() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    client.add_allowed_type(&admin, &WasteType::Metal);
+    client.remove_allowed_type(&admin, &WasteType::Metal);
+    assert_eq!(client.get_allowed_types().len(), 0);
+
+    client.submit_material(&WasteType::Paper, &1000, &recycler, &String::from_str(&env, "Paper"));
+}
+
+#[test]
+fn test_add_allowed_type_is_idempotent() {
+    let env = Env::default();
+    let (client, admin, _) = setup(&env);
+
+    client.add_allowed_type(&admin, &WasteType::Metal);
+    client.add_allowed_type(&admin, &WasteType::Metal);
+
+    assert_eq!(client.get_allowed_types(), vec![&env, WasteType::Metal]);
+    assert_eq!(client.get_config().allowed_waste_types, vec![&env, WasteType::Metal]);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_add_allowed_type_non_admin_fails() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    client.add_allowed_type(&recycler, &WasteType::Metal);
+}
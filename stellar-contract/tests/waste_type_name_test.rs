@@ -0,0 +1,26 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{Env, String};
+use stellar_scavngr_contract::WasteType;
+
+#[test]
+fn test_waste_type_names() {
+    let env = Env::default();
+    let client = common::setup_contract(&env);
+
+    let cases = [
+        (WasteType::Paper, "PAPER"),
+        (WasteType::PetPlastic, "PETPLASTIC"),
+        (WasteType::Plastic, "PLASTIC"),
+        (WasteType::Metal, "METAL"),
+        (WasteType::Glass, "GLASS"),
+    ];
+
+    for (waste_type, expected) in cases {
+        let name = client.get_waste_type_name(&waste_type);
+        assert_eq!(name, String::from_str(&env, expected));
+        assert_eq!(name, client.get_waste_type_string(&waste_type));
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+    let collector = common::register(env, &client, ParticipantRole::Collector, symbol_short!("col"));
+    let manufacturer = common::register(env, &client, ParticipantRole::Manufacturer, symbol_short!("mfr"));
+
+    (client, recycler, collector, manufacturer)
+}
+
+#[test]
+fn test_only_unconfirmed_wastes_returned() {
+    let env = Env::default();
+    let (client, _, collector, manufacturer) = setup(&env);
+
+    let w1 = client.recycle_waste(&WasteType::Metal, &1000, &collector, &0, &0);
+    let w2 = client.recycle_waste(&WasteType::Paper, &2000, &collector, &0, &0);
+    client.transfer_waste_v2(&w1, &collector, &manufacturer, &0, &0);
+    client.transfer_waste_v2(&w2, &collector, &manufacturer, &0, &0);
+
+    assert_eq!(client.get_wastes_awaiting_confirmation(&manufacturer), vec![&env, w1, w2]);
+
+    client.confirm_waste_details(&w1, &collector);
+
+    assert_eq!(client.get_wastes_awaiting_confirmation(&manufacturer), vec![&env, w2]);
+}
+
+#[test]
+fn test_unknown_owner_returns_empty() {
+    let env = Env::default();
+    let (client, _, _, _) = setup(&env);
+
+    assert_eq!(client.get_wastes_awaiting_confirmation(&Address::generate(&env)).len(), 0);
+}
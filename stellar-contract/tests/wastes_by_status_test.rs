@@ -0,0 +1,39 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let recycler = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("rec"));
+
+    (client, admin, recycler)
+}
+
+#[test]
+fn test_wastes_split_by_status() {
+    let env = Env::default();
+    let (client, admin, recycler) = setup(&env);
+
+    let first = client.recycle_waste(&WasteType::Plastic, &1000, &recycler, &0, &0);
+    let second = client.recycle_waste(&WasteType::Metal, &1000, &recycler, &0, &0);
+    let third = client.recycle_waste(&WasteType::Glass, &1000, &recycler, &0, &0);
+
+    client.deactivate_waste(&second, &admin);
+
+    assert_eq!(client.get_wastes_by_status(&recycler, &true), vec![&env, first, third]);
+    assert_eq!(client.get_wastes_by_status(&recycler, &false), vec![&env, second]);
+}
+
+#[test]
+fn test_owner_without_wastes_returns_empty() {
+    let env = Env::default();
+    let (client, _, recycler) = setup(&env);
+
+    assert!(client.get_wastes_by_status(&recycler, &true).is_empty());
+    assert!(client.get_wastes_by_status(&recycler, &false).is_empty());
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_sdk::{symbol_short, vec, Address, Env, String};
+use stellar_scavngr_contract::{ParticipantRole, ScavengerContractClient, WasteType, WeightUnit};
+
+fn setup(env: &Env) -> (ScavengerContractClient<'_>, Address, Address, Address) {
+    let client = common::setup_contract(env);
+
+    let admin = common::init_admin(env, &client);
+    let submitter = common::register(env, &client, ParticipantRole::Collector, symbol_short!("sub"));
+    let verifier = common::register(env, &client, ParticipantRole::Recycler, symbol_short!("ver"));
+
+    (client, admin, submitter, verifier)
+}
+
+#[test]
+fn test_kilogram_submission_stored_in_grams() {
+    let env = Env::default();
+    let (client, admin, submitter, verifier) = setup(&env);
+
+    // Reference submission of 5kg expressed in grams
+    let in_grams = client.submit_material(&WasteType::Metal, &5000, &submitter, &String::from_str(&env, "Cans"));
+    client.verify_material(&in_grams.id, &verifier);
+
+    client.set_weight_unit(&admin, &WeightUnit::Kilograms);
+    assert_eq!(client.get_weight_unit(), WeightUnit::Kilograms);
+    assert_eq!(client.get_config().weight_unit, WeightUnit::Kilograms);
+
+    let in_kg = client.submit_material(&WasteType::Metal, &5, &submitter, &String::from_str(&env, "Cans"));
+    assert_eq!(in_kg.weight, 5000);
+    assert_eq!(client.get_material(&in_kg.id).unwrap().weight, 5000);
+
+    client.verify_material(&in_kg.id, &verifier);
+
+    assert_eq!(client.get_waste_reward(&in_kg.id), client.get_waste_reward(&in_grams.id));
+    assert_eq!(client.get_stats(&submitter).unwrap().total_weight, 10000);
+}
+
+#[test]
+fn test_kilogram_batch_submission_converted() {
+    let env = Env::default();
+    let (client, admin, submitter, _) = setup(&env);
+
+    client.set_weight_unit(&admin, &WeightUnit::Kilograms);
+
+    let materials = vec![
+        &env,
+        (WasteType::Paper, 2u64, String::from_str(&env, "Boxes")),
+        (WasteType::Glass, 3u64, String::from_str(&env, "Jars")),
+    ];
+    let results = client.submit_materials_batch(&materials, &submitter);
+
+    assert_eq!(results.get(0).unwrap().weight, 2000);
+    assert_eq!(results.get(1).unwrap().weight, 3000);
+}
+
+#[test]
+fn test_weight_unit_defaults_to_grams() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    assert_eq!(client.get_weight_unit(), WeightUnit::Grams);
+
+    let material = client.submit_material(&WasteType::Metal, &5, &submitter, &String::from_str(&env, "Cans"));
+    assert_eq!(material.weight, 5);
+}
+
+#[test]
+#[should_panic(expected = "Caller is not the contract admin")]
+fn test_set_weight_unit_non_admin() {
+    let env = Env::default();
+    let (client, _, submitter, _) = setup(&env);
+
+    client.set_weight_unit(&submitter, &WeightUnit::Kilograms);
+}